@@ -1,6 +1,7 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	AccountId, AssetsConfig, AuraConfig, Balance, BalancesConfig, BlockNumber,
+	CouncilMembershipConfig, GenesisConfig, GrandpaConfig, IdentityRegistrarsConfig, Signature,
+	SudoConfig, SystemConfig, VestingConfig, DAYS, GAME_ASSET_ID, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -59,6 +60,17 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				// Council members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+				],
+				// Identity registrars and their fees
+				vec![(get_account_id_from_seed::<sr25519::Public>("Alice"), 0)],
+				// Vesting schedules
+				vec![],
+				// Create the game asset
+				true,
 				true,
 			)
 		},
@@ -107,6 +119,24 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				// Council members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				],
+				// Identity registrars and their fees
+				vec![
+					(get_account_id_from_seed::<sr25519::Public>("Alice"), 0),
+					(get_account_id_from_seed::<sr25519::Public>("Bob"), 0),
+				],
+				// Vesting schedules: team accounts unlock linearly over a year
+				vec![
+					team_vesting(get_account_id_from_seed::<sr25519::Public>("Charlie")),
+					team_vesting(get_account_id_from_seed::<sr25519::Public>("Dave")),
+				],
+				// Create the game asset
+				false,
 				true,
 			)
 		},
@@ -130,6 +160,10 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	council_members: Vec<AccountId>,
+	identity_registrars: Vec<(AccountId, Balance)>,
+	vesting: Vec<(AccountId, BlockNumber, BlockNumber, Balance)>,
+	game_asset: bool,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -149,8 +183,42 @@ fn testnet_genesis(
 		},
 		sudo: SudoConfig {
 			// Assign network admin rights.
-			key: Some(root_key),
+			key: Some(root_key.clone()),
 		},
 		transaction_payment: Default::default(),
+		// Funded with the existential deposit so small fee shares are not lost as dust.
+		treasury: Default::default(),
+		// Seeded through `CouncilMembership`, which initializes the council's members.
+		council: Default::default(),
+		council_membership: CouncilMembershipConfig {
+			members: council_members.try_into().expect("council fits in CouncilMaxMembers"),
+			phantom: Default::default(),
+		},
+		identity_registrars: IdentityRegistrarsConfig { registrars: identity_registrars },
+		// (account, begin, length, liquid): everything above `liquid` unlocks linearly over
+		// `length` blocks starting at `begin`. The accounts must be endowed.
+		vesting: VestingConfig { vesting },
+		assets: if game_asset {
+			game_asset_genesis(root_key, &endowed_accounts)
+		} else {
+			Default::default()
+		},
+	}
+}
+
+/// A team account's vesting schedule: all but `1 << 50` of its endowment unlocks linearly over
+/// 365 days from genesis. The liquid part pays fees and kitty deposits until funds vest.
+fn team_vesting(who: AccountId) -> (AccountId, BlockNumber, BlockNumber, Balance) {
+	(who, 0, 365 * DAYS, 1 << 50)
+}
+
+/// The in-game token, owned by `owner` and handed out to `accounts`. It is sufficient, so players
+/// can pay fees with it without holding native tokens. Fees convert at `GameAssetFeeRate`, one
+/// to one until root changes it.
+fn game_asset_genesis(owner: AccountId, accounts: &[AccountId]) -> AssetsConfig {
+	AssetsConfig {
+		assets: vec![(GAME_ASSET_ID, owner, true, 500)],
+		metadata: vec![(GAME_ASSET_ID, b"Kitty Coin".to_vec(), b"KTC".to_vec(), 12)],
+		accounts: accounts.iter().cloned().map(|k| (GAME_ASSET_ID, k, 1 << 60)).collect(),
 	}
 }
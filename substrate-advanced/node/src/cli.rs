@@ -50,4 +50,8 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Notarize and verify local files against the proof of existence pallet.
+	#[clap(subcommand)]
+	Poe(crate::poe::PoeSubcommand),
 }
@@ -7,6 +7,7 @@ mod service;
 mod cli;
 mod command;
 mod command_helper;
+mod poe;
 mod rpc;
 
 fn main() -> sc_cli::Result<()> {
@@ -0,0 +1,139 @@
+//! Sub-commands to notarize and verify local files against `pallet_poe`.
+//!
+//! `poe hash` only needs the file, while `poe verify` opens the local database the same way
+//! `export-state` does and reads `Proofs` directly, so it works offline against a synced node.
+//!
+//! `pallet_poe` itself accepts any bytes as a claim. These commands follow the file claim scheme
+//! introduced together with them, `PoeModule::claim_of`: a file's claim is the blake2_256 hash of
+//! its content. Claims created by clients that hash files differently will not be found.
+
+use crate::service::FullClient;
+
+use node_template_runtime::{opaque::Block, AccountId, BlockNumber, PoeModule};
+use sc_cli::{BlockNumberOrHash, CliConfiguration, DatabaseParams, PruningParams, SharedParams};
+use sc_client_api::StorageProvider;
+use sp_blockchain::HeaderBackend;
+use sp_core::{hexdisplay::HexDisplay, storage::StorageKey, Decode};
+use sp_runtime::generic::BlockId;
+
+use std::{fs, path::PathBuf, sync::Arc};
+
+const CLAIM_TOO_LONG: &str = "Claim exceeds `MaxClaimLength`";
+
+/// Proof of existence utilities for local files.
+#[derive(Debug, clap::Subcommand)]
+pub enum PoeSubcommand {
+	/// Print the claim of a file, as computed by `PoeModule::claim_of`.
+	Hash(PoeHashCmd),
+
+	/// Check the claim of a file, its owner and its block in the local database.
+	Verify(PoeVerifyCmd),
+}
+
+/// The `poe hash` command.
+#[derive(Debug, clap::Parser)]
+pub struct PoeHashCmd {
+	/// File to notarize.
+	#[clap(parse(from_os_str))]
+	pub file: PathBuf,
+}
+
+impl PoeHashCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let data = fs::read(&self.file)?;
+		let claim = PoeModule::claim_of(&data).ok_or(CLAIM_TOO_LONG)?;
+		println!("0x{}", HexDisplay::from(&claim.into_inner()));
+		Ok(())
+	}
+}
+
+/// The `poe verify` command.
+#[derive(Debug, clap::Parser)]
+pub struct PoeVerifyCmd {
+	/// File to verify.
+	#[clap(parse(from_os_str))]
+	pub file: PathBuf,
+
+	/// Fail unless the claim was recorded at this block, given by hash or number.
+	#[clap(long, value_name = "HASH or NUMBER")]
+	pub at: Option<BlockNumberOrHash>,
+
+	/// Fail unless the claim is owned by this account.
+	#[clap(long, value_name = "SS58")]
+	pub owner: Option<AccountId>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl PoeVerifyCmd {
+	/// Run the command.
+	pub fn run(&self, client: Arc<FullClient>) -> sc_cli::Result<()> {
+		let data = fs::read(&self.file)?;
+		let claim = PoeModule::claim_of(&data).ok_or(CLAIM_TOO_LONG)?;
+		// The claim must still exist, so it is read from the best block.
+		let at = BlockId::<Block>::Hash(client.info().best_hash);
+		let expected_block = match &self.at {
+			Some(expected) => {
+				let id = expected.parse::<Block>()?;
+				let number = client
+					.block_number_from_id(&id)?
+					.ok_or_else(|| format!("Unknown block {}", id))?;
+				Some(number)
+			},
+			None => None,
+		};
+
+		let key = StorageKey(PoeModule::proof_key(&claim));
+		let proof = client
+			.storage(&at, &key)?
+			.map(|data| <(AccountId, BlockNumber)>::decode(&mut &data.0[..]))
+			.transpose()
+			.map_err(|e| format!("decoding proof: {}", e))?;
+
+		let claim = HexDisplay::from(&claim.into_inner()).to_string();
+		let (owner, block) = proof.ok_or_else(|| format!("No claim 0x{} at {}", claim, at))?;
+
+		println!("claim: 0x{}", claim);
+		println!("owner: {}", owner);
+		println!("block: {}", block);
+
+		if let Some(expected) = &self.owner {
+			if *expected != owner {
+				return Err(format!("Claim 0x{} is owned by {}, not {}", claim, owner, expected).into())
+			}
+		}
+		if let Some(expected) = expected_block {
+			if expected != block {
+				return Err(
+					format!("Claim 0x{} was recorded at block {}, not {}", claim, block, expected).into()
+				)
+			}
+		}
+		Ok(())
+	}
+}
+
+impl CliConfiguration for PoeVerifyCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+}
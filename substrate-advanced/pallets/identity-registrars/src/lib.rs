@@ -0,0 +1,58 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet] // pallet_identity没有创世配置，由本模块在创世时登记注册员
+pub mod pallet {
+	use frame_support::{pallet_prelude::*, sp_std::prelude::*, traits::Currency};
+	use frame_system::{pallet_prelude::*, RawOrigin};
+
+	type BalanceOf<T> = <<T as pallet_identity::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	// RegistrarOrigin必须接受root，创世时以root身份登记注册员
+	#[pallet::config] // 定义配置接口
+	pub trait Config: frame_system::Config + pallet_identity::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		// 注册员账户及其收费，按顺序得到注册员编号0, 1, 2...
+		pub registrars: Vec<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { registrars: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (index, (account, fee)) in self.registrars.iter().enumerate() {
+				pallet_identity::Pallet::<T>::add_registrar(RawOrigin::Root.into(), account.clone())
+					.expect("RegistrarOrigin accepts root and MaxRegistrars is not exceeded");
+				pallet_identity::Pallet::<T>::set_fee(
+					RawOrigin::Signed(account.clone()).into(),
+					index as u32,
+					*fee,
+				)
+				.expect("registrar was just added");
+			}
+		}
+	}
+
+	#[pallet::call] // 不包含可调用函数
+	impl<T: Config> Pallet<T> {}
+}
@@ -0,0 +1,30 @@
+use crate::mock::*;
+
+// 1.1 创世时按顺序登记注册员和收费
+#[test]
+fn genesis_adds_registrars_with_fees() {
+	new_test_ext(vec![(1, 5), (2, 0)]).execute_with(|| {
+		let registrars = Identity::registrars();
+		assert_eq!(registrars.len(), 2);
+
+		let first = registrars[0].as_ref().unwrap();
+		assert_eq!((first.account, first.fee), (1, 5));
+		let second = registrars[1].as_ref().unwrap();
+		assert_eq!((second.account, second.fee), (2, 0));
+	});
+}
+
+// 1.2 没有配置注册员
+#[test]
+fn genesis_without_registrars() {
+	new_test_ext(vec![]).execute_with(|| {
+		assert!(Identity::registrars().is_empty());
+	});
+}
+
+// 1.3 超过MaxRegistrars时创世失败
+#[test]
+#[should_panic(expected = "RegistrarOrigin accepts root and MaxRegistrars is not exceeded")]
+fn genesis_panics_on_too_many_registrars() {
+	new_test_ext(vec![(1, 0), (2, 0), (3, 0)]);
+}
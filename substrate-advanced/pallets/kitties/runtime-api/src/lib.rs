@@ -0,0 +1,17 @@
+//! Runtime API definition for pallet-kitties.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// `Profile` is the runtime's view of an account's identity, including registrar judgements.
+	pub trait KittiesApi<AccountId, KittyIndex, Profile> where
+		AccountId: Codec,
+		KittyIndex: Codec,
+		Profile: Codec,
+	{
+		/// kitty的拥有人、DNA和拥有人的身份信息
+		fn kitty(kitty_id: KittyIndex) -> Option<(AccountId, [u8; 16], Profile)>;
+	}
+}
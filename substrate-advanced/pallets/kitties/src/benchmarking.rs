@@ -1,20 +1,65 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-kitties
 
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use crate::Pallet as KittiesModule;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	sp_std::prelude::*,
+	traits::{Currency, EnsureOrigin, Get, ReservableCurrency},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
-		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+	force_remove {
+		// 最坏情况：拥有者的kitty列表已满，且被移除的kitty排在最后
+		let owner: T::AccountId = account("owner", 0, 0);
+		T::Currency::make_free_balance_be(
+			&owner,
+			T::Currency::minimum_balance() + T::KittyPrice::get() + T::KittyPrice::get(),
+		);
+		T::Currency::reserve(&owner, T::KittyPrice::get())?;
+
+		let owned: Vec<Kitty> = (0..T::MaxKittyLength::get())
+			.map(|i| {
+				let mut dna = [0u8; 16];
+				dna[..4].copy_from_slice(&i.to_le_bytes());
+				Kitty(dna)
+			})
+			.collect();
+		let kitty = owned.last().cloned().expect("MaxKittyLength is not zero; qed");
+		let kitty_id = T::KittyIndex::from(0_u32);
+		Kitties::<T>::insert(kitty_id, &kitty);
+		KittyOnwer::<T>::insert(kitty_id, &owner);
+		KittyOnwerHistory::<T>::insert(
+			&owner,
+			BoundedVec::<Kitty, T::MaxKittyLength>::try_from(owned).expect("within MaxKittyLength; qed"),
+		);
+		let origin = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(origin, kitty_id)
+	verify {
+		assert_eq!(Kitties::<T>::get(kitty_id), None);
+		assert_eq!(T::Currency::reserved_balance(&owner), 0_u32.into());
+	}
+
+	approve_operator {
+		let owner: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+	}: _(RawOrigin::Signed(owner.clone()), operator.clone())
+	verify {
+		assert!(KittiesModule::<T>::is_operator(&owner, &operator));
+	}
+
+	cancel_operator {
+		let owner: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+		Operators::<T>::insert(&owner, &operator, ());
+	}: _(RawOrigin::Signed(owner.clone()), operator.clone())
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert!(!KittiesModule::<T>::is_operator(&owner, &operator));
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(KittiesModule, crate::mock::new_test_ext(), crate::mock::Test);
 }
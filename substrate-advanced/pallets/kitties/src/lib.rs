@@ -2,14 +2,25 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
 
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::{pallet_prelude::{*, DispatchResult}, traits::Randomness,traits::Currency, traits::ReservableCurrency};
+	use frame_support::{pallet_prelude::{*, DispatchResult}, traits::Randomness,traits::Currency, traits::ReservableCurrency, traits::{Imbalance, OnUnbalanced}};
 	use frame_system::pallet_prelude::{*, OriginFor};
 	use sp_io::hashing::blake2_128;
 	use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded, CheckedAdd};
+	use crate::WeightInfo;
 
 	// type KittyIndex  = u32;
 
@@ -23,6 +34,8 @@ pub mod pallet {
 
 	type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	type NegativeImbalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -44,6 +57,14 @@ pub mod pallet {
 		// 定义kitty价格
 		type KittyPrice: Get<BalanceOf<Self>>;
 
+		// 可以强制移除违规kitty的管理员权限，例如root或议会多数
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+		// 强制移除时罚没的质押token的去向，例如国库
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		// 交易权重，由benchmarking.rs生成
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
@@ -66,6 +87,11 @@ pub mod pallet {
 	#[pallet::getter(fn all_kts_owned)]
 	pub type KittyOnwerHistory<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<Kitty, T::MaxKittyLength>, ValueQuery>;
 
+	// 拥有者授权的操作账户：(拥有者, 操作账户)，例如允许某个合约转移拥有者的kitty
+	#[pallet::storage]
+	#[pallet::getter(fn operators)]
+	pub type Operators<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -73,6 +99,11 @@ pub mod pallet {
 		KittyCreate(T::AccountId, T::KittyIndex, Kitty),
 		KittyBreed(T::AccountId, T::KittyIndex, Kitty),
 		KittyTransfor(T::AccountId,T::KittyIndex, T::AccountId),
+		// 管理员强制移除kitty并罚没质押：(原拥有者, kitty id, 罚没金额)
+		KittyForceRemoved(T::AccountId, T::KittyIndex, BalanceOf<T>),
+		// 授权/取消授权操作账户：(拥有者, 操作账户)
+		OperatorApproved(T::AccountId, T::AccountId),
+		OperatorCancelled(T::AccountId, T::AccountId),
 	}
 	#[pallet::error]
 	pub enum Error<T> {
@@ -81,6 +112,7 @@ pub mod pallet {
 		KittyIndexOverflow,
 		BalanceNotEnough,
 		OverLimitOnwerForKitty,
+		NotOperator,
 	}
 
 	#[pallet::call]
@@ -202,9 +234,68 @@ pub mod pallet {
 			Ok(())
 		}
 
+		#[pallet::weight(T::WeightInfo::force_remove())]
+		pub fn force_remove(origin: OriginFor<T>, kitty_id: T::KittyIndex) -> DispatchResult {
+			// 1.只有ForceOrigin才可以强制移除违规kitty
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			// 2.判断kitty id是否存在
+			let kitty = Self::get_kitty(kitty_id).map_err(|_| Error::<T>::InvalidKittyId)?;
+			let owner = Self::kitty_onwer(kitty_id).ok_or(Error::<T>::InvalidKittyId)?;
+
+			// 3.罚没拥有者的质押token，交给Slashed处理
+			let (imbalance, _) = T::Currency::slash_reserved(&owner, T::KittyPrice::get());
+			let slashed = imbalance.peek();
+			T::Slashed::on_unbalanced(imbalance);
+
+			// 4.删除kitty及其拥有者
+			Kitties::<T>::remove(kitty_id);
+			KittyOnwer::<T>::remove(kitty_id);
+
+			// 5.把当前的kitty从拥有者的kitty列表中删除
+			KittyOnwerHistory::<T>::mutate(&owner, |kitties_vec| {
+				if let Some(index) = kitties_vec.iter().position(|kitty_in_vec| kitty_in_vec == &kitty) {
+					kitties_vec.remove(index);
+				}
+			});
+
+			Self::deposit_event(Event::<T>::KittyForceRemoved(owner, kitty_id, slashed));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::approve_operator())]
+		pub fn approve_operator(origin: OriginFor<T>, operator: T::AccountId) -> DispatchResult {
+			// 1.判断当前用户是否是有效用户
+			let owner = ensure_signed(origin)?;
+
+			// 2.记录授权，重复授权不会出错
+			Operators::<T>::insert(&owner, &operator, ());
+
+			Self::deposit_event(Event::<T>::OperatorApproved(owner, operator));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::cancel_operator())]
+		pub fn cancel_operator(origin: OriginFor<T>, operator: T::AccountId) -> DispatchResult {
+			// 1.判断当前用户是否是有效用户
+			let owner = ensure_signed(origin)?;
+
+			// 2.判断是否授权过该操作账户
+			ensure!(Self::is_operator(&owner, &operator), Error::<T>::NotOperator);
+			Operators::<T>::remove(&owner, &operator);
+
+			Self::deposit_event(Event::<T>::OperatorCancelled(owner, operator));
+			Ok(())
+		}
+
 	}
 
 	impl<T: Config> Pallet<T> {
+		// operator是否被owner授权操作其kitty
+		pub fn is_operator(owner: &T::AccountId, operator: &T::AccountId) -> bool {
+			Operators::<T>::contains_key(owner, operator)
+		}
+
 		fn random_value(sender: &T::AccountId) -> [u8; 16]{
 			let payload = (
 				T::Randomness::random_seed(),
@@ -1,2 +1,76 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, Error, Event as KittiesEvent};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, ReservableCurrency},
+};
+use sp_runtime::DispatchError;
+
+// ======================================================= 1.强制移除用例 ==================================================
+// 1.1 测试强制移除kitty：非ForceOrigin调用的场景
+#[test]
+fn test_force_remove_bad_origin() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+
+		assert_noop!(KittiesModule::force_remove(Origin::signed(1), 0), DispatchError::BadOrigin);
+		assert_noop!(KittiesModule::force_remove(Origin::signed(2), 0), DispatchError::BadOrigin);
+	})
+}
+
+// 1.2 测试强制移除kitty：kitty不存在的场景
+#[test]
+fn test_force_remove_no_such_kitty() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(KittiesModule::force_remove(Origin::root(), 0), Error::<Test>::InvalidKittyId);
+	})
+}
+
+// 1.3 测试强制移除kitty：罚没拥有者的质押并交给Slashed
+#[test]
+fn test_force_remove_slashes_to_slashed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_ok!(KittiesModule::create(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 20);
+
+		assert_ok!(KittiesModule::force_remove(Origin::root(), 0));
+
+		assert_eq!(KittiesModule::kitties(0), None);
+		assert_eq!(KittiesModule::kitty_onwer(0), None);
+		assert_eq!(KittiesModule::all_kts_owned(1).len(), 1);
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Balances::total_balance(&1), 90);
+		assert_eq!(Balances::free_balance(SLASHED_POT), 10);
+		System::assert_last_event(KittiesEvent::<Test>::KittyForceRemoved(1, 0, 10).into());
+	})
+}
+
+// ======================================================= 2.授权操作账户用例 ==============================================
+// 2.1 测试授权操作账户：授权和取消授权
+#[test]
+fn test_approve_and_cancel_operator() {
+	new_test_ext().execute_with(|| {
+		assert!(!KittiesModule::is_operator(&1, &2));
+
+		assert_ok!(KittiesModule::approve_operator(Origin::signed(1), 2));
+		assert!(KittiesModule::is_operator(&1, &2));
+		// 授权只对授权人有效
+		assert!(!KittiesModule::is_operator(&2, &1));
+		System::assert_last_event(KittiesEvent::<Test>::OperatorApproved(1, 2).into());
+
+		assert_ok!(KittiesModule::cancel_operator(Origin::signed(1), 2));
+		assert!(!KittiesModule::is_operator(&1, &2));
+		System::assert_last_event(KittiesEvent::<Test>::OperatorCancelled(1, 2).into());
+	})
+}
+
+// 2.2 测试取消授权：没有授权过该操作账户的场景
+#[test]
+fn test_cancel_operator_not_operator() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(KittiesModule::approve_operator(Origin::signed(1), 2));
+
+		assert_noop!(KittiesModule::cancel_operator(Origin::signed(2), 1), Error::<Test>::NotOperator);
+		assert_noop!(KittiesModule::cancel_operator(Origin::signed(1), 3), Error::<Test>::NotOperator);
+	})
+}
@@ -0,0 +1,68 @@
+//! Weights for pallet_kitties
+//!
+//! `force_remove`, `approve_operator` and `cancel_operator` have benchmarks but no measured weight
+//! yet; they are charged `10_000` plus the storage they touch until `scripts/benchmark_weights.sh`
+//! regenerates this file.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use frame_support::sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_kitties.
+pub trait WeightInfo {
+	fn force_remove() -> Weight;
+	fn approve_operator() -> Weight;
+	fn cancel_operator() -> Weight;
+}
+
+/// Weights for pallet_kitties charged by the runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: KittiesModule KittyOnwer (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: KittiesModule KittyOnwerHistory (r:1 w:1)
+	fn force_remove() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: KittiesModule Operators (r:0 w:1)
+	fn approve_operator() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: KittiesModule Operators (r:1 w:1)
+	fn cancel_operator() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: KittiesModule Kitties (r:1 w:1)
+	// Storage: KittiesModule KittyOnwer (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: KittiesModule KittyOnwerHistory (r:1 w:1)
+	fn force_remove() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	// Storage: KittiesModule Operators (r:0 w:1)
+	fn approve_operator() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: KittiesModule Operators (r:1 w:1)
+	fn cancel_operator() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -0,0 +1,183 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet] // 紧急暂停：按pallet或单个调用冻结交易，无需升级runtime
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		sp_std::{marker::PhantomData, prelude::*},
+		traits::{Contains, GetCallMetadata, PalletInfoAccess},
+	};
+	use frame_system::pallet_prelude::*;
+	use crate::WeightInfo;
+
+	// pallet名称或调用名称，与construct_runtime!中的名称一致，例如b"KittiesModule"、b"transfor"
+	pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+	#[pallet::config] // 定义配置接口
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		// 可以暂停、恢复调用的管理员权限，例如root或议会多数
+		type PauseOrigin: EnsureOrigin<Self::Origin>;
+
+		// pallet名称和调用名称的最大长度
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		// 不能被暂停、始终放行的pallet名称，例如提供inherent的Timestamp，以及处理事故所需的System、议会
+		type UnpausablePallets: Contains<Vec<u8>>;
+
+		// 交易权重，由benchmarking.rs生成
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub (super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage] // 整个pallet被暂停：pallet名称 => ()
+	pub type PausedPallets<T: Config> = StorageMap<_, Blake2_128Concat, NameOf<T>, (), OptionQuery>;
+
+	#[pallet::storage] // 单个调用被暂停：(pallet名称, 调用名称) => ()
+	pub type PausedCalls<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		NameOf<T>,
+		Blake2_128Concat,
+		NameOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::event] // 定义事件回调
+	#[pallet::generate_deposit(pub (super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		// 已暂停：(pallet名称, 调用名称)，调用名称为None表示整个pallet
+		Paused(NameOf<T>, Option<NameOf<T>>),
+		// 已恢复：(pallet名称, 调用名称)
+		Unpaused(NameOf<T>, Option<NameOf<T>>),
+	}
+
+	#[pallet::error] // 定义错误信息
+	pub enum Error<T> {
+		NameTooLong,
+		AlreadyPaused,
+		NotPaused,
+		Unpausable,
+	}
+
+	#[pallet::call] // 包含可调用函数
+	impl<T: Config> Pallet<T> {
+		// 暂停整个pallet（call_name为None）或其中的单个调用
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			call_name: Option<Vec<u8>>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			// 本pallet总是放行，暂停它只会留下一条无效的记录
+			ensure!(
+				pallet_name != Self::name().as_bytes() && !T::UnpausablePallets::contains(&pallet_name),
+				Error::<T>::Unpausable
+			);
+			let (pallet_name, call_name) = Self::bounded_names(pallet_name, call_name)?;
+
+			match &call_name {
+				None => {
+					ensure!(!PausedPallets::<T>::contains_key(&pallet_name), Error::<T>::AlreadyPaused);
+					PausedPallets::<T>::insert(&pallet_name, ());
+				},
+				Some(call) => {
+					ensure!(
+						!PausedCalls::<T>::contains_key(&pallet_name, call),
+						Error::<T>::AlreadyPaused
+					);
+					PausedCalls::<T>::insert(&pallet_name, call, ());
+				},
+			}
+
+			Self::deposit_event(Event::Paused(pallet_name, call_name));
+			Ok(())
+		}
+
+		// 恢复被暂停的pallet或调用；恢复pallet不会影响单独暂停的调用
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			call_name: Option<Vec<u8>>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+			let (pallet_name, call_name) = Self::bounded_names(pallet_name, call_name)?;
+
+			match &call_name {
+				None => PausedPallets::<T>::take(&pallet_name),
+				Some(call) => PausedCalls::<T>::take(&pallet_name, call),
+			}
+			.ok_or(Error::<T>::NotPaused)?;
+
+			Self::deposit_event(Event::Unpaused(pallet_name, call_name));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		// 调用是否被暂停；超长的名称不可能被暂停
+		pub fn is_paused(pallet_name: &[u8], call_name: &[u8]) -> bool {
+			let pallet_name = match NameOf::<T>::try_from(pallet_name.to_vec()) {
+				Ok(name) => name,
+				Err(_) => return false,
+			};
+			if PausedPallets::<T>::contains_key(&pallet_name) {
+				return true
+			}
+			NameOf::<T>::try_from(call_name.to_vec())
+				.map(|call_name| PausedCalls::<T>::contains_key(&pallet_name, &call_name))
+				.unwrap_or(false)
+		}
+
+		fn bounded_names(
+			pallet_name: Vec<u8>,
+			call_name: Option<Vec<u8>>,
+		) -> Result<(NameOf<T>, Option<NameOf<T>>), Error<T>> {
+			let pallet_name = NameOf::<T>::try_from(pallet_name).map_err(|_| Error::<T>::NameTooLong)?;
+			let call_name = call_name
+				.map(NameOf::<T>::try_from)
+				.transpose()
+				.map_err(|_| Error::<T>::NameTooLong)?;
+			Ok((pallet_name, call_name))
+		}
+	}
+
+	// 用作frame_system::Config::BaseCallFilter：放行未被暂停的调用，本pallet自身和UnpausablePallets中的
+	// pallet始终放行
+	pub struct NotPaused<T>(PhantomData<T>);
+
+	impl<T: Config> Contains<<T as frame_system::Config>::Call> for NotPaused<T>
+	where
+		<T as frame_system::Config>::Call: GetCallMetadata,
+	{
+		fn contains(call: &<T as frame_system::Config>::Call) -> bool {
+			let metadata = call.get_call_metadata();
+			let pallet_name = metadata.pallet_name.as_bytes();
+			pallet_name == Pallet::<T>::name().as_bytes() ||
+				T::UnpausablePallets::contains(&pallet_name.to_vec()) ||
+				!Pallet::<T>::is_paused(pallet_name, metadata.function_name.as_bytes())
+		}
+	}
+}
@@ -0,0 +1,151 @@
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, dispatch::Dispatchable, traits::Contains};
+use sp_runtime::DispatchError;
+
+fn remark() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![1] })
+}
+
+// 以签名账户身份经过BaseCallFilter分发调用
+fn dispatch(call: Call) -> Result<(), DispatchError> {
+	call.dispatch(Origin::signed(1)).map(|_| ()).map_err(|e| e.error)
+}
+
+fn call_filtered() -> Result<(), DispatchError> {
+	Err(frame_system::Error::<Test>::CallFiltered.into())
+}
+
+// ======================================================= 1.暂停用例 ==================================================
+// 1.1 暂停单个调用：该调用被拒绝，同一pallet的其他调用不受影响
+#[test]
+fn pause_call_filters_only_that_call() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pause::pause(Origin::root(), b"System".to_vec(), Some(b"remark".to_vec())));
+
+		assert_eq!(dispatch(remark()), call_filtered());
+		assert_ok!(dispatch(Call::System(frame_system::Call::remark_with_event { remark: vec![1] })));
+		assert!(Pause::is_paused(b"System", b"remark"));
+		System::assert_last_event(
+			Event::Pause(crate::Event::Paused(
+				b"System".to_vec().try_into().unwrap(),
+				Some(b"remark".to_vec().try_into().unwrap()),
+			)),
+		);
+	});
+}
+
+// 1.2 暂停整个pallet：该pallet的所有调用都被拒绝
+#[test]
+fn pause_pallet_filters_all_its_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pause::pause(Origin::root(), b"System".to_vec(), None));
+
+		assert_eq!(dispatch(remark()), call_filtered());
+		assert_eq!(
+			dispatch(Call::System(frame_system::Call::remark_with_event { remark: vec![1] })),
+			call_filtered()
+		);
+	});
+}
+
+// 1.3 本pallet不能被暂停，它的调用始终放行
+#[test]
+fn pause_pallet_itself_stays_callable() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Pause::pause(Origin::root(), b"Pause".to_vec(), None), Error::<Test>::Unpausable);
+		assert_noop!(
+			Pause::pause(Origin::root(), b"Pause".to_vec(), Some(b"unpause".to_vec())),
+			Error::<Test>::Unpausable
+		);
+
+		let unpause = Call::Pause(crate::Call::unpause { pallet_name: b"Pause".to_vec(), call_name: None });
+		assert!(NotPaused::<Test>::contains(&unpause));
+	});
+}
+
+// 1.4 只有PauseOrigin才可以暂停
+#[test]
+fn pause_requires_pause_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pause::pause(Origin::signed(1), b"System".to_vec(), None),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+// 1.5 重复暂停、名称超长
+#[test]
+fn pause_fails_when_already_paused_or_name_too_long() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pause::pause(Origin::root(), b"System".to_vec(), None));
+		assert_noop!(
+			Pause::pause(Origin::root(), b"System".to_vec(), None),
+			Error::<Test>::AlreadyPaused
+		);
+		assert_noop!(
+			Pause::pause(Origin::root(), vec![b'x'; 17], None),
+			Error::<Test>::NameTooLong
+		);
+		assert_noop!(
+			Pause::pause(Origin::root(), b"System".to_vec(), Some(vec![b'x'; 17])),
+			Error::<Test>::NameTooLong
+		);
+	});
+}
+
+// 1.6 UnpausablePallets中的pallet不能被暂停，其调用也不能被单独暂停
+#[test]
+fn pause_rejects_unpausable_pallets() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pause::pause(Origin::root(), b"Timestamp".to_vec(), None),
+			Error::<Test>::Unpausable
+		);
+		assert_noop!(
+			Pause::pause(Origin::root(), b"Timestamp".to_vec(), Some(b"set".to_vec())),
+			Error::<Test>::Unpausable
+		);
+	});
+}
+
+// ======================================================= 2.恢复用例 ==================================================
+// 2.1 恢复后调用重新可用
+#[test]
+fn unpause_allows_the_call_again() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pause::pause(Origin::root(), b"System".to_vec(), Some(b"remark".to_vec())));
+		assert_ok!(Pause::unpause(Origin::root(), b"System".to_vec(), Some(b"remark".to_vec())));
+
+		assert_ok!(dispatch(remark()));
+		assert!(!Pause::is_paused(b"System", b"remark"));
+	});
+}
+
+// 2.2 恢复pallet不会恢复单独暂停的调用
+#[test]
+fn unpause_pallet_keeps_paused_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pause::pause(Origin::root(), b"System".to_vec(), Some(b"remark".to_vec())));
+		assert_ok!(Pause::pause(Origin::root(), b"System".to_vec(), None));
+		assert_ok!(Pause::unpause(Origin::root(), b"System".to_vec(), None));
+
+		assert_eq!(dispatch(remark()), call_filtered());
+	});
+}
+
+// 2.3 恢复未暂停的调用
+#[test]
+fn unpause_fails_when_not_paused() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pause::unpause(Origin::root(), b"System".to_vec(), None),
+			Error::<Test>::NotPaused
+		);
+		assert_noop!(
+			Pause::unpause(Origin::signed(1), b"System".to_vec(), None),
+			DispatchError::BadOrigin
+		);
+	});
+}
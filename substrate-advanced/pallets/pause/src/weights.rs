@@ -0,0 +1,50 @@
+//! Weights for pallet_pause
+//!
+//! Only storage accesses so far: `pause` and `unpause` are charged `10_000` plus the entries they
+//! read and write until `scripts/benchmark_weights.sh` replaces this file with measured weights.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use frame_support::sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_pause.
+pub trait WeightInfo {
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+}
+
+/// Weights for pallet_pause charged by the runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Pause PausedCalls (r:1 w:1)
+	fn pause() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Pause PausedCalls (r:1 w:1)
+	fn unpause() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Pause PausedCalls (r:1 w:1)
+	fn pause() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Pause PausedCalls (r:1 w:1)
+	fn unpause() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -0,0 +1,20 @@
+//! Runtime API definition for pallet-poe.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+sp_api::decl_runtime_apis! {
+	/// `Profile` is the runtime's view of an account's identity, including registrar judgements.
+	pub trait PoeApi<AccountId, BlockNumber, Profile> where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Profile: Codec,
+	{
+		/// 存证的拥有人、创建区块和拥有人的身份信息
+		fn claim(proof: Vec<u8>) -> Option<(AccountId, BlockNumber, Profile)>;
+		/// 账户拥有的存证
+		fn claims_of(who: AccountId) -> Vec<Vec<u8>>;
+	}
+}
@@ -1,20 +1,157 @@
-//! Benchmarking setup for pallet-template
+//! Benchmarking setup for pallet-poe
 
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
+use crate::Pallet as PoeModule;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	sp_std::{prelude::*, vec},
+	traits::{Currency, EnsureOrigin, Get, ReservableCurrency},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+
+// 生成长度为len的存证
+fn claim<T: Config>(len: u32) -> BoundedVec<u8, T::MaxClaimLength> {
+	BoundedVec::try_from(vec![u8::MAX; len as usize]).expect("len is within MaxClaimLength; qed")
+}
+
+// 生成count个长度为len、互不相同的存证
+fn claims<T: Config>(count: u32, len: u32) -> Vec<BoundedVec<u8, T::MaxClaimLength>> {
+	(0..count)
+		.map(|i| {
+			let mut claim = vec![0u8; len as usize];
+			claim[0] = i as u8;
+			BoundedVec::try_from(claim).expect("len is within MaxClaimLength; qed")
+		})
+		.collect()
+}
+
+// 给who足够支付押金的余额
+fn fund<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+// 把who的存证列表填到只剩一个空位（最坏情况：索引最长）
+fn fill_index<T: Config>(who: &T::AccountId, len: u32) {
+	let claims = claims::<T>(T::MaxClaimsPerAccount::get() - 1, len);
+	ClaimsOf::<T>::insert(who, BoundedVec::try_from(claims).expect("fewer than MaxClaimsPerAccount; qed"));
+}
+
+// 配置锚定签名账户并填满当前区块的锚定批次（最坏情况：新存证顺延到下一个区块的批次）
+fn fill_anchor_queue<T: Config>(len: u32) {
+	AnchorSigner::<T>::put(sp_core::ed25519::Public([1u8; 32]));
+	let claims = claims::<T>(T::MaxAnchorBatch::get(), len);
+	let block_number = frame_system::Pallet::<T>::block_number();
+	AnchorQueue::<T>::insert(block_number, BoundedVec::try_from(claims).expect("exactly MaxAnchorBatch; qed"));
+}
+
+// 由owner创建存证，并授权给whitelisted_caller管理（最坏情况：需要额外读取授权信息）
+fn delegated_claim<T: Config>(len: u32) -> (T::AccountId, BoundedVec<u8, T::MaxClaimLength>) {
+	let owner: T::AccountId = account("owner", 0, 0);
+	let caller: T::AccountId = whitelisted_caller();
+	let claim = claim::<T>(len);
+	fill_index::<T>(&owner, len);
+	fund::<T>(&owner);
+	T::Currency::reserve(&owner, T::ClaimDeposit::get()).expect("owner is funded; qed");
+	ClaimDeposits::<T>::insert(&claim, (owner.clone(), T::ClaimDeposit::get()));
+	Proofs::<T>::insert(&claim, (owner.clone(), T::BlockNumber::default()));
+	ClaimsOf::<T>::mutate(&owner, |claims| claims.try_push(claim.clone()))
+		.expect("index has a free slot; qed");
+	Delegates::<T>::insert(&owner, &caller, ());
+	(caller, claim)
+}
 
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	create_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let claim = claim::<T>(l);
+		fill_index::<T>(&caller, l);
+		fill_anchor_queue::<T>(l);
+		fund::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), claim.clone())
+	verify {
+		let block_number = frame_system::Pallet::<T>::block_number();
+		assert_eq!(Proofs::<T>::get(&claim), Some((caller, block_number)));
+		assert_eq!(NextAnchorBatch::<T>::get(), block_number + 1u32.into());
+	}
+
+	revoke_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let (caller, claim) = delegated_claim::<T>(l);
+	}: _(RawOrigin::Signed(caller), claim.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&claim), None);
+		assert_eq!(ClaimDeposits::<T>::get(&claim), None);
+	}
+
+	trans_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let (caller, claim) = delegated_claim::<T>(l);
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		fill_index::<T>(&receiver, l);
+	}: _(RawOrigin::Signed(caller), claim.clone(), receiver.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&claim), Some((receiver, frame_system::Pallet::<T>::block_number())));
+	}
+
+	add_delegate {
+		let caller: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone())
+	verify {
+		assert_eq!(Delegates::<T>::get(&caller, &delegate), Some(()));
+	}
+
+	remove_delegate {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		Delegates::<T>::insert(&caller, &delegate, ());
+	}: _(RawOrigin::Signed(caller.clone()), delegate.clone())
+	verify {
+		assert_eq!(Delegates::<T>::get(&caller, &delegate), None);
+	}
+
+	force_revoke_claim {
+		let l in 1 .. T::MaxClaimLength::get();
+		let (_, claim) = delegated_claim::<T>(l);
+		let origin = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(origin, claim.clone(), 0)
+	verify {
+		assert_eq!(Proofs::<T>::get(&claim), None);
+	}
+
+	set_anchor_signer {
+		let signer = sp_core::ed25519::Public([1u8; 32]);
+		let origin = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(origin, signer.clone())
+	verify {
+		assert_eq!(AnchorSigner::<T>::get(), Some(signer));
+	}
+
+	submit_anchor {
+		let c in 1 .. T::MaxAnchorBatch::get();
+		let block_number = T::BlockNumber::default();
+		let claims = claims::<T>(c, T::MaxClaimLength::get());
+		AnchorQueue::<T>::insert(block_number, BoundedVec::try_from(claims).expect("c is within MaxAnchorBatch; qed"));
+		let signature = sp_core::ed25519::Signature([0u8; 64]);
+		frame_system::Pallet::<T>::set_block_number(block_number + 1u32.into());
+	}: _(RawOrigin::None, block_number, [1u8; 32], signature)
+	verify {
+		assert!(Anchors::<T>::get(block_number).is_some());
+	}
+
+	drop_anchor_batch {
+		fill_anchor_queue::<T>(T::MaxClaimLength::get());
+		let block_number = frame_system::Pallet::<T>::block_number();
+		let origin = T::ForceOrigin::successful_origin();
+	}: _<T::Origin>(origin, block_number)
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert!(AnchorQueue::<T>::get(block_number).is_empty());
 	}
 
-	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
+	impl_benchmark_test_suite!(PoeModule, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -2,28 +2,100 @@
 
 pub use pallet::*;
 
+use sp_runtime::KeyTypeId;
+
+// 锚定签名密钥的类型，节点需要通过author_insertKey插入该类型的ed25519密钥
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"poe!");
+
 #[cfg(test)]
 mod mock;
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet] // 定义功能模块
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	use frame_support::{
+		pallet_prelude::*,
+		sp_std::prelude::*,
+		traits::{Currency, OnUnbalanced, ReservableCurrency},
+	};
+	use frame_system::{offchain::{SendTransactionTypes, SubmitTransaction}, pallet_prelude::*};
+	use sp_core::ed25519;
+	use sp_runtime::{
+		offchain::{
+			http,
+			storage::{StorageRetrievalError, StorageValueRef},
+			Duration, StorageKind,
+		},
+		traits::One,
+	};
+	use crate::{WeightInfo, KEY_TYPE};
+
+	// 默认的外部时间戳日志地址，可以通过offchain本地存储ANCHOR_ENDPOINT_KEY覆盖
+	pub const DEFAULT_ANCHOR_ENDPOINT: &str = "http://localhost:8000/anchor";
+	pub const ANCHOR_ENDPOINT_KEY: &[u8] = b"poe::anchor-endpoint";
+	const ANCHOR_LOCK_PREFIX: &[u8] = b"poe::anchor-lock";
+	// 每次offchain worker最多锚定的批次数量，以及同一批次重新发送前等待的区块数
+	const MAX_BATCHES_PER_RUN: usize = 5;
+	const ANCHOR_RETRY_BLOCKS: u32 = 10;
+	const HTTP_TIMEOUT_MS: u64 = 3_000;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
 
 	#[pallet::config] // 定义配置接口
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config + SendTransactionTypes<Call<Self>> {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		type MaxClaimLength: Get<u32>;
+
+		// 每个账户最多拥有的存证数量
+		type MaxClaimsPerAccount: Get<u32>;
+
+		// 可以强制撤销存证的管理员权限，例如root
+		type ForceOrigin: EnsureOrigin<Self::Origin>;
+
+		// 创建存证时质押押金的货币
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		// 每个存证的押金，撤销时退还，被强制撤销时罚没
+		#[pallet::constant]
+		type ClaimDeposit: Get<BalanceOf<Self>>;
+
+		// 被罚没的押金的去向，例如国库
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		// 每个锚定批次最多包含的存证数量
+		type MaxAnchorBatch: Get<u32>;
+
+		// 锚定回执无签名交易的优先级
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		// 交易权重，由benchmarking.rs生成
+		type WeightInfo: WeightInfo;
 	}
 
+	// 存储版本，v1新增了ClaimsOf索引，v2在ClaimDeposits中记录质押人
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage] // 定义存储单元
+	#[pallet::getter(fn proofs)]
 	pub(super) type Proofs<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
@@ -32,12 +104,77 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage] // 每个账户拥有的存证列表，由create_claim、revoke_claim和trans_claim维护
+	#[pallet::getter(fn claims_of)]
+	pub type ClaimsOf<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxClaimsPerAccount>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage] // 存证押金：存证 => (质押人, 金额)。押金一直由创建存证的账户质押，转移存证不会改变质押人；
+	// 引入押金之前创建的存证没有押金
+	#[pallet::getter(fn claim_deposits)]
+	pub type ClaimDeposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BoundedVec<u8, T::MaxClaimLength>,
+		(T::AccountId, BalanceOf<T>),
+		OptionQuery,
+	>;
+
+	#[pallet::storage] // 等待offchain worker锚定的存证批次：区块号 => 存证。一个区块的批次满了之后，
+	// 新存证顺延到之后区块的批次，所以批次的区块号不早于存证创建的区块
+	pub(super) type AnchorQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<BoundedVec<u8, T::MaxClaimLength>, T::MaxAnchorBatch>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage] // 正在填充的锚定批次的区块号，只会向后移动
+	pub(super) type NextAnchorBatch<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage] // 已锚定的批次：区块号 => (批次摘要, 外部日志回执的哈希)
+	#[pallet::getter(fn anchors)]
+	pub type Anchors<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, ([u8; 32], [u8; 32]), OptionQuery>;
+
+	#[pallet::storage] // 锚定签名账户，只接受该密钥签名的锚定回执
+	#[pallet::getter(fn anchor_signer)]
+	pub type AnchorSigner<T: Config> = StorageValue<_, ed25519::Public, OptionQuery>;
+
+	#[pallet::storage] // 存证拥有人授权的管理账户：(拥有人, 被授权人) => ()
+	pub(super) type Delegates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::event] // 定义事件回调
 	#[pallet::generate_deposit(pub (super) fn deposit_event)] //系统的事件，用于更方便触发事件
 	pub enum Event<T: Config> {
 		ClaimCreated(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
 		ClaimRevoked(T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
 		ClaimTrans(T::AccountId,T::AccountId, BoundedVec<u8, T::MaxClaimLength>),
+		DelegateAdded(T::AccountId, T::AccountId),
+		DelegateRemoved(T::AccountId, T::AccountId),
+		// 管理员强制撤销存证：(原拥有人, 存证, 原因代码)
+		ClaimForceRevoked(T::AccountId, BoundedVec<u8, T::MaxClaimLength>, u8),
+		// 强制撤销时罚没押金：(质押人, 罚没金额)
+		DepositSlashed(T::AccountId, BalanceOf<T>),
+		AnchorSignerSet(ed25519::Public),
+		// 批次已锚定：(区块号, 批次摘要, 回执哈希)
+		ClaimsAnchored(T::BlockNumber, [u8; 32], [u8; 32]),
+		// 管理员丢弃无法锚定的批次：(区块号)
+		AnchorBatchDropped(T::BlockNumber),
 	}
 
 	#[pallet::error] // 定义错误信息
@@ -45,12 +182,65 @@ pub mod pallet {
 		ProofAlreadyClaimed,
 		NoSuchProof,
 		NotProofOwner,
+		DelegateAlreadyExists,
+		NoSuchDelegate,
+		CannotDelegateToSelf,
+		TooManyClaims,
+		NoPendingAnchor,
+		BalanceNotEnough,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+				.saturating_add(crate::migrations::v2::migrate::<T>())
+		}
+
+		// 把等待锚定的批次摘要签名后发送到外部时间戳日志，再用无签名交易把回执哈希记录到链上
+		fn offchain_worker(now: T::BlockNumber) {
+			if let Err(e) = Self::anchor_pending(now) {
+				log::warn!(target: "runtime::poe", "anchoring claims at {:?} failed: {}", now, e);
+			}
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::submit_anchor { block_number, receipt, signature } = call {
+				let signer = AnchorSigner::<T>::get().ok_or(InvalidTransaction::BadSigner)?;
+				let claims = AnchorQueue::<T>::get(block_number);
+				// 还在填充的批次不能锚定
+				if claims.is_empty() || *block_number >= <frame_system::Pallet<T>>::block_number() {
+					return InvalidTransaction::Stale.into()
+				}
+
+				// 回执必须由锚定签名账户对(区块号, 批次摘要, 回执)签名
+				let digest = Self::batch_digest(block_number, &claims);
+				let message = (block_number, digest, receipt).encode();
+				if !sp_io::crypto::ed25519_verify(signature, &message, &signer) {
+					return InvalidTransaction::BadProof.into()
+				}
+
+				ValidTransaction::with_tag_prefix("PoeAnchor")
+					.priority(T::UnsignedPriority::get())
+					.and_provides(block_number)
+					.longevity(ANCHOR_RETRY_BLOCKS.into())
+					.propagate(true)
+					.build()
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
 	}
 
 
 	#[pallet::call] // 包含可调用函数
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(1_000)] // 设置权重，可转换成交易费用，以此来防止类似拒绝服务的攻击
+		#[pallet::weight(T::WeightInfo::create_claim(proof.len() as u32))] // 设置权重，可转换成交易费用，以此来防止类似拒绝服务的攻击
 		pub fn create_claim(
 			origin: OriginFor<T>,
 			proof: BoundedVec<u8, T::MaxClaimLength>,
@@ -65,16 +255,30 @@ pub mod pallet {
 
 			let current_block = <frame_system::Pallet<T>>::block_number();
 
+			// 质押押金
+			let deposit = T::ClaimDeposit::get();
+			T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::BalanceNotEnough)?;
+			ClaimDeposits::<T>::insert(&proof, (&sender, deposit));
+
 			// 不存在执行插入操作，key是存证的hash值，value是当前的发送方跟当前交易所在的区块高度，使用block_number这个系统函数进行查询
 			Proofs::<T>::insert(&proof, (&sender, current_block));
 
+			// 加入拥有人的存证列表，超过上限则整个交易回滚
+			ClaimsOf::<T>::try_mutate(&sender, |claims| claims.try_push(proof.clone()))
+				.map_err(|_| Error::<T>::TooManyClaims)?;
+
+			// 配置了锚定签名账户时才加入等待锚定的批次
+			if AnchorSigner::<T>::exists() {
+				Self::queue_anchor(current_block, proof.clone());
+			}
+
 			// 发送事件
 			Self::deposit_event(Event::ClaimCreated(sender, proof));
 
 			Ok(())
 		}
 
-		#[pallet::weight(10_000)]
+		#[pallet::weight(T::WeightInfo::revoke_claim(proof.len() as u32))]
 		pub fn revoke_claim(
 			origin: OriginFor<T>,
 			proof: BoundedVec<u8, T::MaxClaimLength>, //
@@ -82,19 +286,24 @@ pub mod pallet {
 
 			let sender = ensure_signed(origin)?;
 
-			ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+			let (owner, _) = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
 
-			let (owner, _) = Proofs::<T>::get(&proof).expect("All proofs must have an owner!");
-
-			ensure!(sender == owner, Error::<T>::NotProofOwner);
+			// 拥有人或者拥有人授权的账户才可以撤销
+			ensure!(Self::can_manage(&sender, &owner), Error::<T>::NotProofOwner);
 
 			Proofs::<T>::remove(&proof);
+			Self::remove_from_index(&owner, &proof);
+
+			// 退还质押人的押金
+			if let Some((depositor, deposit)) = ClaimDeposits::<T>::take(&proof) {
+				T::Currency::unreserve(&depositor, deposit);
+			}
 
 			Self::deposit_event(Event::ClaimRevoked(sender, proof));
 			Ok(())
 		}
 
-		#[pallet::weight(100_000)]
+		#[pallet::weight(T::WeightInfo::trans_claim(proof.len() as u32))]
 		pub fn trans_claim(
 			origin: OriginFor<T>,
 			proof: BoundedVec<u8, T::MaxClaimLength>,
@@ -105,18 +314,232 @@ pub mod pallet {
 			let sender = ensure_signed(origin)?;
 
 			// 检查存证是否存在
-			ensure!(Proofs::<T>::contains_key(&proof), Error::<T>::NoSuchProof);
+			let (owner, _) = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
 
-			// 检查发送方是否为存证的owner
-			let (owner, _) = Proofs::<T>::get(&proof).expect("All proofs must have an owner!");
-			ensure!(sender == owner, Error::<T>::NotProofOwner);
+			// 检查发送方是否为存证的owner或者owner授权的账户
+			ensure!(Self::can_manage(&sender, &owner), Error::<T>::NotProofOwner);
 
-			// 直接将原来的覆盖
+			// 直接将原来的覆盖，押金仍由原质押人承担，接收者不需要质押
 			let current_block = <frame_system::Pallet<T>>::block_number();
 			Proofs::<T>::insert(&proof, (&receiver, current_block));
 
+			// 从原拥有人的存证列表移到接收者的存证列表
+			Self::remove_from_index(&owner, &proof);
+			ClaimsOf::<T>::try_mutate(&receiver, |claims| claims.try_push(proof.clone()))
+				.map_err(|_| Error::<T>::TooManyClaims)?;
+
 			Self::deposit_event(Event::ClaimTrans(sender, receiver,proof));
 			Ok(())
 		}
+
+		#[pallet::weight(T::WeightInfo::add_delegate())]
+		pub fn add_delegate(origin: OriginFor<T>, delegate: T::AccountId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(sender != delegate, Error::<T>::CannotDelegateToSelf);
+			ensure!(!Delegates::<T>::contains_key(&sender, &delegate), Error::<T>::DelegateAlreadyExists);
+
+			// 授权后，delegate可以撤销和转移sender名下的所有存证
+			Delegates::<T>::insert(&sender, &delegate, ());
+
+			Self::deposit_event(Event::DelegateAdded(sender, delegate));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::remove_delegate())]
+		pub fn remove_delegate(origin: OriginFor<T>, delegate: T::AccountId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(Delegates::<T>::contains_key(&sender, &delegate), Error::<T>::NoSuchDelegate);
+
+			Delegates::<T>::remove(&sender, &delegate);
+
+			Self::deposit_event(Event::DelegateRemoved(sender, delegate));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::force_revoke_claim(proof.len() as u32))]
+		pub fn force_revoke_claim(
+			origin: OriginFor<T>,
+			proof: BoundedVec<u8, T::MaxClaimLength>,
+			reason: u8,
+		) -> DispatchResult {
+			// 只有ForceOrigin（如root）才可以强制撤销欺诈存证，reason为撤销原因代码
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let (owner, _) = Proofs::<T>::get(&proof).ok_or(Error::<T>::NoSuchProof)?;
+
+			Proofs::<T>::remove(&proof);
+			Self::remove_from_index(&owner, &proof);
+
+			// 罚没质押人的押金，交给Slashed处理
+			if let Some((depositor, deposit)) = ClaimDeposits::<T>::take(&proof) {
+				let (imbalance, _) = T::Currency::slash_reserved(&depositor, deposit);
+				T::Slashed::on_unbalanced(imbalance);
+				Self::deposit_event(Event::DepositSlashed(depositor, deposit));
+			}
+
+			Self::deposit_event(Event::ClaimForceRevoked(owner, proof, reason));
+			Ok(())
+		}
+
+		// 更换锚定签名密钥后，队列中尚未锚定的批次由持有新密钥的节点重新签名提交
+		#[pallet::weight(T::WeightInfo::set_anchor_signer())]
+		pub fn set_anchor_signer(origin: OriginFor<T>, signer: ed25519::Public) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			AnchorSigner::<T>::put(&signer);
+
+			Self::deposit_event(Event::AnchorSignerSet(signer));
+			Ok(())
+		}
+
+		// 由offchain worker提交的无签名交易，签名已经在validate_unsigned中校验
+		#[pallet::weight(T::WeightInfo::submit_anchor(T::MaxAnchorBatch::get()))]
+		pub fn submit_anchor(
+			origin: OriginFor<T>,
+			block_number: T::BlockNumber,
+			receipt: [u8; 32],
+			_signature: ed25519::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			ensure!(block_number < <frame_system::Pallet<T>>::block_number(), Error::<T>::NoPendingAnchor);
+			let claims = AnchorQueue::<T>::take(block_number);
+			ensure!(!claims.is_empty(), Error::<T>::NoPendingAnchor);
+
+			let digest = Self::batch_digest(&block_number, &claims);
+			Anchors::<T>::insert(block_number, (digest, receipt));
+
+			Self::deposit_event(Event::ClaimsAnchored(block_number, digest, receipt));
+			Ok(())
+		}
+
+		// 丢弃无法再锚定的批次，例如更换签名密钥后没有节点持有新密钥，避免批次一直留在队列中被重复发送
+		#[pallet::weight(T::WeightInfo::drop_anchor_batch())]
+		pub fn drop_anchor_batch(origin: OriginFor<T>, block_number: T::BlockNumber) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			let claims = AnchorQueue::<T>::take(block_number);
+			ensure!(!claims.is_empty(), Error::<T>::NoPendingAnchor);
+
+			Self::deposit_event(Event::AnchorBatchDropped(block_number));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		// 存证拥有人本人或者被拥有人授权的账户可以管理存证
+		fn can_manage(who: &T::AccountId, owner: &T::AccountId) -> bool {
+			who == owner || Delegates::<T>::contains_key(owner, who)
+		}
+
+		fn remove_from_index(owner: &T::AccountId, proof: &BoundedVec<u8, T::MaxClaimLength>) {
+			ClaimsOf::<T>::mutate(owner, |claims| claims.retain(|claim| claim != proof));
+		}
+
+		// 把存证加入正在填充的批次，批次已满时顺延到下一个区块的批次，不会让create_claim失败
+		fn queue_anchor(current_block: T::BlockNumber, proof: BoundedVec<u8, T::MaxClaimLength>) {
+			let mut batch = NextAnchorBatch::<T>::get().max(current_block);
+			let mut claims = AnchorQueue::<T>::get(batch);
+			if claims.len() as u32 >= T::MaxAnchorBatch::get() {
+				// 批次号只向后移动，之后区块的批次一定还是空的
+				batch += One::one();
+				claims = Default::default();
+			}
+			if claims.try_push(proof).is_ok() {
+				AnchorQueue::<T>::insert(batch, claims);
+			}
+			NextAnchorBatch::<T>::put(batch);
+		}
+
+		// 批次摘要：对(区块号, 存证列表)的blake2_256哈希
+		pub fn batch_digest(
+			block_number: &T::BlockNumber,
+			claims: &[BoundedVec<u8, T::MaxClaimLength>],
+		) -> [u8; 32] {
+			(block_number, claims).using_encoded(sp_io::hashing::blake2_256)
+		}
+
+		fn anchor_pending(now: T::BlockNumber) -> Result<(), &'static str> {
+			// 只有本地keystore中有锚定签名密钥的节点才负责锚定
+			let signer = match AnchorSigner::<T>::get() {
+				Some(signer) if sp_io::crypto::ed25519_public_keys(KEY_TYPE).contains(&signer) => signer,
+				_ => return Ok(()),
+			};
+
+			// 只锚定已经结束填充的批次，顺延到之后区块的批次要等到链高度达到该区块
+			let closed = AnchorQueue::<T>::iter().filter(|(block_number, _)| *block_number <= now);
+			for (block_number, claims) in closed.take(MAX_BATCHES_PER_RUN) {
+				if !Self::acquire_anchor_lock(&block_number, now) {
+					continue
+				}
+
+				let digest = Self::batch_digest(&block_number, &claims);
+				let digest_signature = sp_io::crypto::ed25519_sign(KEY_TYPE, &signer, &digest)
+					.ok_or("signing the digest failed")?;
+				let receipt = Self::post_digest(&block_number, &digest, &signer, &digest_signature)?;
+
+				let message = (&block_number, digest, receipt).encode();
+				let signature = sp_io::crypto::ed25519_sign(KEY_TYPE, &signer, &message)
+					.ok_or("signing the receipt failed")?;
+				let call = Call::submit_anchor { block_number, receipt, signature };
+				SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into())
+					.map_err(|_| "submitting the anchor transaction failed")?;
+			}
+			Ok(())
+		}
+
+		// 同一批次在ANCHOR_RETRY_BLOCKS个区块内只发送一次，避免在交易上链之前重复写入外部日志
+		fn acquire_anchor_lock(block_number: &T::BlockNumber, now: T::BlockNumber) -> bool {
+			let key = (ANCHOR_LOCK_PREFIX, block_number).encode();
+			StorageValueRef::persistent(&key)
+				.mutate(|sent_at: Result<Option<T::BlockNumber>, StorageRetrievalError>| match sent_at {
+					Ok(Some(at)) if now < at + ANCHOR_RETRY_BLOCKS.into() => Err(()),
+					_ => Ok(now),
+				})
+				.is_ok()
+		}
+
+		// 把签名的批次摘要POST到外部日志，返回回执（响应内容）的哈希
+		fn post_digest(
+			block_number: &T::BlockNumber,
+			digest: &[u8; 32],
+			signer: &ed25519::Public,
+			signature: &ed25519::Signature,
+		) -> Result<[u8; 32], &'static str> {
+			let endpoint = sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, ANCHOR_ENDPOINT_KEY)
+				.unwrap_or_else(|| DEFAULT_ANCHOR_ENDPOINT.as_bytes().to_vec());
+			let endpoint =
+				core::str::from_utf8(&endpoint).map_err(|_| "anchor endpoint is not valid utf8")?;
+
+			let body = (block_number, digest, signer, signature).encode();
+			let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+			let pending = http::Request::post(endpoint, vec![body])
+				.add_header("Content-Type", "application/octet-stream")
+				.deadline(deadline)
+				.send()
+				.map_err(|_| "sending the anchor request failed")?;
+			let response = pending
+				.try_wait(deadline)
+				.map_err(|_| "anchor request timed out")?
+				.map_err(|_| "anchor request failed")?;
+			if response.code != 200 {
+				return Err("anchor endpoint returned an error")
+			}
+
+			Ok(sp_io::hashing::blake2_256(&response.body().collect::<Vec<u8>>()))
+		}
+
+		// 文件存证的约定格式：文件内容的blake2_256哈希。这是随节点poe命令行新增的约定，
+		// pallet本身接受任意字节作为存证；客户端要与命令行互通，需要用同样的方法生成存证
+		pub fn claim_of(data: &[u8]) -> Option<BoundedVec<u8, T::MaxClaimLength>> {
+			sp_io::hashing::blake2_256(data).to_vec().try_into().ok()
+		}
+
+		// 存证在Proofs中的存储key（Blake2_128Concat），用于在runtime之外（如节点命令行）直接读取数据库状态
+		pub fn proof_key(proof: &BoundedVec<u8, T::MaxClaimLength>) -> Vec<u8> {
+			Proofs::<T>::hashed_key_for(proof)
+		}
 	}
 }
@@ -0,0 +1,54 @@
+//! Storage migrations for pallet-poe
+
+use crate::{BalanceOf, ClaimDeposits, ClaimsOf, Config, Pallet, Proofs};
+use frame_support::{
+	traits::{Get, GetStorageVersion, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	// v0 -> v1：遍历已有的Proofs，为每个拥有人建立ClaimsOf索引
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		for (proof, (owner, _)) in Proofs::<T>::iter() {
+			reads += 2;
+			// 超过MaxClaimsPerAccount的存证仍然保留在Proofs中，只是不进入索引
+			if ClaimsOf::<T>::try_mutate(&owner, |claims| claims.try_push(proof)).is_ok() {
+				writes += 1;
+			}
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	// v1 -> v2：ClaimDeposits从押金金额改为(质押人, 金额)。v2之前转移存证时押金随存证转到接收者名下，
+	// 所以现在的拥有人就是质押人
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 2 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: Weight = 0;
+		ClaimDeposits::<T>::translate::<BalanceOf<T>, _>(|proof, deposit| {
+			translated += 1;
+			Proofs::<T>::get(&proof).map(|(owner, _)| (owner, deposit))
+		});
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(1 + 2 * translated, 1 + translated)
+	}
+}
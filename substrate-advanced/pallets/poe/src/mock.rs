@@ -1,13 +1,15 @@
 use crate as pallet_poe;
-use frame_support::traits::{ConstU16, ConstU32, ConstU64};
+use frame_support::traits::{ConstU16, ConstU32, ConstU64, GenesisBuild};
 use frame_system as system;
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestXt},
 	traits::{BlakeTwo256, IdentityLookup},
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+pub type Extrinsic = TestXt<Call, ()>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
 // Configure a mock runtime to test the pallet.
@@ -18,7 +20,8 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		PoeModule: pallet_poe::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		PoeModule: pallet_poe::{Pallet, Call, Storage, Event<T>, ValidateUnsigned},
 	}
 );
 
@@ -40,7 +43,7 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -49,12 +52,44 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
 impl pallet_poe::Config for Test {
 	type Event = Event;
 	type MaxClaimLength = ConstU32<512>;
+	type MaxClaimsPerAccount = ConstU32<3>;
+	type MaxAnchorBatch = ConstU32<4>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Currency = Balances;
+	type ClaimDeposit = ConstU64<10>;
+	type Slashed = ();
+	type WeightInfo = ();
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Test
+where
+	Call: From<C>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = Extrinsic;
 }
 
-// Build genesis storage according to the mock runtime.
+// Build genesis storage according to the mock runtime. Accounts 0 to 5 hold 100 each.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: (0..=5).map(|who| (who, 100)).collect() }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
 }
@@ -133,3 +133,513 @@ fn test_transform_claim_does_not_belong_to_currentowner() {
 		);
 	})
 }
+
+// ============================================== 4.文件存证用例 ==========================================================
+// 4.1 测试文件存证用例：同一文件生成的存证一致，并且可以用来创建存证
+#[test]
+fn test_claim_of_file_work() {
+	new_test_ext().execute_with(|| {
+		let data = b"hello substrate";
+		let bounded_claim = PoeModule::claim_of(data).unwrap();
+
+		assert_eq!(bounded_claim.len(), 32);
+		assert_eq!(PoeModule::claim_of(data), Some(bounded_claim.clone()));
+		assert_ne!(PoeModule::claim_of(b"hello polkadot"), Some(bounded_claim.clone()));
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), bounded_claim.clone()));
+		assert_eq!(
+			Proofs::<Test>::get(&bounded_claim),
+			Some((1, frame_system::Pallet::<Test>::block_number()))
+		);
+	})
+}
+
+// ============================================== 5.授权管理用例 ==========================================================
+// 5.1 测试授权用例：被授权账户可以撤销和转移拥有人的存证
+#[test]
+fn test_delegate_manage_claim_work() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0,1];
+		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
+		let _ = PoeModule::create_claim(Origin::signed(1),bounded_claim.clone());
+
+		assert_ok!(PoeModule::add_delegate(Origin::signed(1), 2u64));
+		assert_eq!(Delegates::<Test>::get(1, 2), Some(()));
+
+		assert_ok!(PoeModule::trans_claim(Origin::signed(2), bounded_claim.clone(), 3u64));
+		assert_eq!(
+			Proofs::<Test>::get(&bounded_claim),
+			Some((3, frame_system::Pallet::<Test>::block_number()))
+		);
+
+		// 存证转移给3之后，1的授权账户不能再管理该存证
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(2), bounded_claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+
+		let _ = PoeModule::create_claim(Origin::signed(1),BoundedVec::try_from(vec![2,3]).unwrap());
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(2), BoundedVec::try_from(vec![2,3]).unwrap()));
+	})
+}
+
+// 5.2 测试授权用例：不能重复授权，也不能授权给自己
+#[test]
+fn test_add_delegate_failed() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(PoeModule::add_delegate(Origin::signed(1), 1u64), Error::<Test>::CannotDelegateToSelf);
+
+		assert_ok!(PoeModule::add_delegate(Origin::signed(1), 2u64));
+		assert_noop!(PoeModule::add_delegate(Origin::signed(1), 2u64), Error::<Test>::DelegateAlreadyExists);
+	})
+}
+
+// 5.3 测试取消授权用例：取消后被授权账户不能再管理存证
+#[test]
+fn test_remove_delegate_work() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0,1];
+		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
+		let _ = PoeModule::create_claim(Origin::signed(1),bounded_claim.clone());
+		let _ = PoeModule::add_delegate(Origin::signed(1), 2u64);
+
+		assert_ok!(PoeModule::remove_delegate(Origin::signed(1), 2u64));
+		assert_eq!(Delegates::<Test>::get(1, 2), None);
+
+		assert_noop!(
+			PoeModule::revoke_claim(Origin::signed(2), bounded_claim.clone()),
+			Error::<Test>::NotProofOwner
+		);
+		assert_noop!(PoeModule::remove_delegate(Origin::signed(1), 2u64), Error::<Test>::NoSuchDelegate);
+	})
+}
+
+// ============================================== 6.强制撤销用例 ==========================================================
+// 6.1 测试强制撤销用例：root可以撤销任何存证，并发出带原因代码的事件
+#[test]
+fn test_force_revoke_claim_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let claim = vec![0,1];
+		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
+		let _ = PoeModule::create_claim(Origin::signed(1),bounded_claim.clone());
+
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), bounded_claim.clone(), 7));
+		assert_eq!(Proofs::<Test>::get(&bounded_claim), None);
+		System::assert_last_event(crate::Event::<Test>::ClaimForceRevoked(1, bounded_claim.clone(), 7).into());
+
+		assert_noop!(
+			PoeModule::force_revoke_claim(Origin::root(), bounded_claim.clone(), 7),
+			Error::<Test>::NoSuchProof
+		);
+	})
+}
+
+// 6.2 测试强制撤销用例：普通账户不能强制撤销
+#[test]
+fn test_force_revoke_claim_bad_origin() {
+	new_test_ext().execute_with(|| {
+		let claim = vec![0,1];
+		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(claim.clone()).unwrap();
+		let _ = PoeModule::create_claim(Origin::signed(1),bounded_claim.clone());
+
+		assert_noop!(
+			PoeModule::force_revoke_claim(Origin::signed(1), bounded_claim.clone(), 7),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	})
+}
+
+// ============================================== 7.存证索引用例 ==========================================================
+// 7.1 测试存证索引用例：创建、转移、撤销时维护拥有人的存证列表
+#[test]
+fn test_claims_of_index_work() {
+	new_test_ext().execute_with(|| {
+		let claim_1 = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+		let claim_2 = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![2,3]).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim_1.clone()));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim_2.clone()));
+		assert_eq!(PoeModule::claims_of(1).into_inner(), vec![claim_1.clone(), claim_2.clone()]);
+
+		assert_ok!(PoeModule::trans_claim(Origin::signed(1), claim_1.clone(), 2u64));
+		assert_eq!(PoeModule::claims_of(1).into_inner(), vec![claim_2.clone()]);
+		assert_eq!(PoeModule::claims_of(2).into_inner(), vec![claim_1.clone()]);
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), claim_2.clone()));
+		assert!(PoeModule::claims_of(1).is_empty());
+
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), claim_1.clone(), 0));
+		assert!(PoeModule::claims_of(2).is_empty());
+	})
+}
+
+// 7.2 测试存证索引用例：超过每个账户的存证上限
+#[test]
+fn test_too_many_claims() {
+	new_test_ext().execute_with(|| {
+		for i in 0..3u8 {
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), BoundedVec::try_from(vec![i]).unwrap()));
+		}
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(1), BoundedVec::try_from(vec![3]).unwrap()),
+			Error::<Test>::TooManyClaims
+		);
+
+		let _ = PoeModule::create_claim(Origin::signed(2), BoundedVec::try_from(vec![3]).unwrap());
+		assert_noop!(
+			PoeModule::trans_claim(Origin::signed(2), BoundedVec::try_from(vec![3]).unwrap(), 1u64),
+			Error::<Test>::TooManyClaims
+		);
+	})
+}
+
+// 7.3 测试迁移用例：从已有的Proofs构建存证索引
+#[test]
+fn test_migrate_to_v1_work() {
+	use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PoeModule>();
+		let claim_1 = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+		let claim_2 = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![2,3]).unwrap();
+		Proofs::<Test>::insert(&claim_1, (1, 0));
+		Proofs::<Test>::insert(&claim_2, (2, 0));
+
+		crate::migrations::v1::migrate::<Test>();
+
+		assert_eq!(PoeModule::claims_of(1).into_inner(), vec![claim_1.clone()]);
+		assert_eq!(PoeModule::claims_of(2).into_inner(), vec![claim_2.clone()]);
+		assert_eq!(PoeModule::on_chain_storage_version(), 1);
+
+		// 已经迁移过的不会重复迁移
+		crate::migrations::v1::migrate::<Test>();
+		assert_eq!(PoeModule::claims_of(1).len(), 1);
+	})
+}
+
+// 7.4 测试迁移用例：ClaimDeposits记录质押人，质押人为当前拥有人
+#[test]
+fn test_migrate_to_v2_work() {
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<PoeModule>();
+		let claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+		Proofs::<Test>::insert(&claim, (2, 0));
+		// v1的格式：存证 => 押金金额
+		unhashed::put(&ClaimDeposits::<Test>::hashed_key_for(&claim), &10u64);
+
+		crate::migrations::v2::migrate::<Test>();
+
+		assert_eq!(PoeModule::claim_deposits(&claim), Some((2, 10)));
+		assert_eq!(PoeModule::on_chain_storage_version(), 2);
+	})
+}
+
+// ============================================== 8.链下锚定用例 ==========================================================
+mod anchor {
+	use super::*;
+	use codec::{Decode, Encode};
+	use frame_support::{traits::Hooks, unsigned::ValidateUnsigned};
+	use sp_core::{
+		ed25519,
+		offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt},
+		Pair,
+	};
+	use sp_keystore::{testing::KeyStore, KeystoreExt, SyncCryptoStore};
+	use sp_runtime::transaction_validity::{InvalidTransaction, TransactionSource};
+	use std::sync::Arc;
+
+	const SIGNER_SEED: &str = "//Alice";
+
+	// 在测试环境中注册offchain、交易池和keystore扩展，offchain的HTTP请求由TestOffchainExt模拟的服务端应答
+	fn offchain_test_ext() -> (
+		sp_io::TestExternalities,
+		Arc<parking_lot::RwLock<testing::OffchainState>>,
+		Arc<parking_lot::RwLock<testing::PoolState>>,
+		ed25519::Public,
+	) {
+		let (offchain, offchain_state) = testing::TestOffchainExt::new();
+		let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+		let keystore = KeyStore::new();
+		let signer = SyncCryptoStore::ed25519_generate_new(&keystore, crate::KEY_TYPE, Some(SIGNER_SEED)).unwrap();
+
+		let mut t = new_test_ext();
+		t.register_extension(OffchainWorkerExt::new(offchain.clone()));
+		t.register_extension(OffchainDbExt::new(offchain));
+		t.register_extension(TransactionPoolExt::new(pool));
+		t.register_extension(KeystoreExt(Arc::new(keystore)));
+		(t, offchain_state, pool_state, signer)
+	}
+
+	fn sign(message: &[u8]) -> ed25519::Signature {
+		ed25519::Pair::from_string(SIGNER_SEED, None).unwrap().sign(message)
+	}
+
+	// 8.1 测试锚定用例：offchain worker把签名的批次摘要发送到外部日志，并提交包含回执哈希的无签名交易
+	#[test]
+	fn test_offchain_worker_anchor_work() {
+		let (mut t, offchain_state, pool_state, signer) = offchain_test_ext();
+		t.execute_with(|| {
+			System::set_block_number(1);
+			let claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+			assert_ok!(PoeModule::set_anchor_signer(Origin::root(), signer.clone()));
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
+
+			let digest = PoeModule::batch_digest(&1, &[claim.clone()]);
+			let receipt = b"receipt-1".to_vec();
+			offchain_state.write().expect_request(testing::PendingRequest {
+				method: "POST".into(),
+				uri: DEFAULT_ANCHOR_ENDPOINT.into(),
+				headers: vec![("Content-Type".into(), "application/octet-stream".into())],
+				body: (1u64, digest, signer.clone(), sign(&digest)).encode(),
+				response: Some(receipt.clone()),
+				sent: true,
+				..Default::default()
+			});
+
+			PoeModule::offchain_worker(1);
+
+			let tx = pool_state.write().transactions.pop().unwrap();
+			assert!(pool_state.read().transactions.is_empty());
+			let tx = Extrinsic::decode(&mut &*tx).unwrap();
+			assert_eq!(tx.signature, None);
+
+			let receipt_hash = sp_io::hashing::blake2_256(&receipt);
+			let call = crate::Call::submit_anchor {
+				block_number: 1,
+				receipt: receipt_hash,
+				signature: sign(&(1u64, digest, receipt_hash).encode()),
+			};
+			assert_eq!(tx.call, mock::Call::PoeModule(call.clone()));
+			// 无签名交易在之后的区块中校验和执行
+			System::set_block_number(2);
+			assert!(PoeModule::validate_unsigned(TransactionSource::Local, &call).is_ok());
+
+			// 同一批次在重试间隔内不会重复发送
+			PoeModule::offchain_worker(2);
+			assert!(pool_state.read().transactions.is_empty());
+
+			if let crate::Call::submit_anchor { block_number, receipt, signature } = call {
+				assert_ok!(PoeModule::submit_anchor(Origin::none(), block_number, receipt, signature));
+			}
+			assert_eq!(PoeModule::anchors(1), Some((digest, receipt_hash)));
+			System::assert_last_event(crate::Event::<Test>::ClaimsAnchored(1, digest, receipt_hash).into());
+		})
+	}
+
+	// 8.2 测试锚定用例：不是锚定签名账户签名的回执会被拒绝
+	#[test]
+	fn test_submit_anchor_bad_signature() {
+		let (mut t, _, _, signer) = offchain_test_ext();
+		t.execute_with(|| {
+			System::set_block_number(1);
+			let claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+			assert_ok!(PoeModule::set_anchor_signer(Origin::root(), signer.clone()));
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
+
+			let digest = PoeModule::batch_digest(&1, &[claim.clone()]);
+			let call = crate::Call::submit_anchor {
+				block_number: 1,
+				receipt: [1u8; 32],
+				signature: sign(&(1u64, digest, [1u8; 32]).encode()),
+			};
+			// 还在填充的批次不能锚定
+			assert_eq!(
+				PoeModule::validate_unsigned(TransactionSource::External, &call),
+				Err(InvalidTransaction::Stale.into())
+			);
+
+			System::set_block_number(2);
+			AnchorSigner::<Test>::kill();
+			assert_eq!(
+				PoeModule::validate_unsigned(TransactionSource::External, &call),
+				Err(InvalidTransaction::BadSigner.into())
+			);
+
+			AnchorSigner::<Test>::put(signer);
+			let forged = crate::Call::submit_anchor {
+				block_number: 1,
+				receipt: [2u8; 32],
+				signature: sign(&(1u64, digest, [1u8; 32]).encode()),
+			};
+			assert_eq!(
+				PoeModule::validate_unsigned(TransactionSource::External, &forged),
+				Err(InvalidTransaction::BadProof.into())
+			);
+			assert!(PoeModule::validate_unsigned(TransactionSource::External, &call).is_ok());
+		})
+	}
+
+	// 8.3 测试锚定用例：没有配置锚定签名账户时不加入批次；批次已满时顺延到下一个区块的批次，不会让创建失败
+	#[test]
+	fn test_anchor_batch_overflows_to_next_block() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let claim = |i: u8| BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![i]).unwrap();
+			assert_ok!(PoeModule::create_claim(Origin::signed(0), claim(9)));
+			assert_eq!(AnchorQueue::<Test>::iter().count(), 0);
+
+			assert_ok!(PoeModule::set_anchor_signer(Origin::root(), ed25519::Public([1u8; 32])));
+			for i in 0..5u8 {
+				assert_ok!(PoeModule::create_claim(Origin::signed(i as u64), claim(i)));
+			}
+			assert_eq!(AnchorQueue::<Test>::get(1).into_inner(), vec![claim(0), claim(1), claim(2), claim(3)]);
+			assert_eq!(AnchorQueue::<Test>::get(2).into_inner(), vec![claim(4)]);
+
+			// 到了区块2继续填充顺延的批次，批次2在区块2结束之前不能锚定
+			System::set_block_number(2);
+			assert_ok!(PoeModule::create_claim(Origin::signed(5), claim(5)));
+			assert_eq!(AnchorQueue::<Test>::get(2).into_inner(), vec![claim(4), claim(5)]);
+			assert_noop!(
+				PoeModule::submit_anchor(Origin::none(), 2, [0u8; 32], ed25519::Signature([0u8; 64])),
+				Error::<Test>::NoPendingAnchor
+			);
+
+			System::set_block_number(3);
+			assert_ok!(PoeModule::submit_anchor(Origin::none(), 2, [0u8; 32], ed25519::Signature([0u8; 64])));
+			assert_noop!(
+				PoeModule::submit_anchor(Origin::none(), 2, [0u8; 32], ed25519::Signature([0u8; 64])),
+				Error::<Test>::NoPendingAnchor
+			);
+		})
+	}
+
+	// 8.4 测试锚定用例：更换签名密钥后旧密钥签名的回执被拒绝，排队的批次由新密钥重新签名，或者由管理员丢弃
+	#[test]
+	fn test_anchor_signer_rotation() {
+		let (mut t, _, pool_state, signer) = offchain_test_ext();
+		t.execute_with(|| {
+			System::set_block_number(1);
+			let claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+			assert_ok!(PoeModule::set_anchor_signer(Origin::root(), signer.clone()));
+			assert_ok!(PoeModule::create_claim(Origin::signed(1), claim.clone()));
+
+			// 新密钥不在本地keystore中
+			let rotated = ed25519::Pair::from_string("//Bob", None).unwrap();
+			assert_ok!(PoeModule::set_anchor_signer(Origin::root(), rotated.public()));
+
+			System::set_block_number(2);
+			let digest = PoeModule::batch_digest(&1, &[claim.clone()]);
+			let message = (1u64, digest, [1u8; 32]).encode();
+			let stale = crate::Call::submit_anchor { block_number: 1, receipt: [1u8; 32], signature: sign(&message) };
+			assert_eq!(
+				PoeModule::validate_unsigned(TransactionSource::External, &stale),
+				Err(InvalidTransaction::BadProof.into())
+			);
+
+			// 只持有旧密钥的节点不再发送批次
+			PoeModule::offchain_worker(2);
+			assert!(pool_state.read().transactions.is_empty());
+
+			// 新密钥签名的回执可以锚定排队的批次
+			let resigned =
+				crate::Call::submit_anchor { block_number: 1, receipt: [1u8; 32], signature: rotated.sign(&message) };
+			assert!(PoeModule::validate_unsigned(TransactionSource::External, &resigned).is_ok());
+
+			// 没有节点持有新密钥时，只有管理员可以丢弃批次
+			assert_noop!(PoeModule::drop_anchor_batch(Origin::signed(1), 1), sp_runtime::DispatchError::BadOrigin);
+			assert_ok!(PoeModule::drop_anchor_batch(Origin::root(), 1));
+			assert!(AnchorQueue::<Test>::get(1).is_empty());
+			System::assert_last_event(crate::Event::<Test>::AnchorBatchDropped(1).into());
+
+			assert_eq!(
+				PoeModule::validate_unsigned(TransactionSource::External, &resigned),
+				Err(InvalidTransaction::Stale.into())
+			);
+			assert_noop!(PoeModule::drop_anchor_batch(Origin::root(), 1), Error::<Test>::NoPendingAnchor);
+			// 存证本身不受影响
+			assert_eq!(Proofs::<Test>::get(&claim), Some((1, 1)));
+		})
+	}
+}
+
+// ============================================== 9.押金用例 ==========================================================
+// 9.1 测试押金用例：创建时质押押金，撤销时退还
+#[test]
+fn test_claim_deposit_reserved_and_returned() {
+	new_test_ext().execute_with(|| {
+		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), bounded_claim.clone()));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(PoeModule::claim_deposits(&bounded_claim), Some((1, 10)));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(1), bounded_claim.clone()));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(PoeModule::claim_deposits(&bounded_claim), None);
+	})
+}
+
+// 9.2 测试押金用例：余额不足时不能创建存证
+#[test]
+fn test_claim_deposit_balance_not_enough() {
+	new_test_ext().execute_with(|| {
+		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+
+		assert_noop!(
+			PoeModule::create_claim(Origin::signed(9), bounded_claim.clone()),
+			Error::<Test>::BalanceNotEnough
+		);
+	})
+}
+
+// 9.3 测试押金用例：转移不会动用接收者的余额，押金仍由质押人承担，撤销和强制撤销都针对质押人
+#[test]
+fn test_claim_deposit_stays_with_depositor() {
+	new_test_ext().execute_with(|| {
+		let claim_1 = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+		let claim_2 = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![2,3]).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim_1.clone()));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), claim_2.clone()));
+
+		// 账户9没有余额，也可以接收存证
+		assert_ok!(PoeModule::trans_claim(Origin::signed(1), claim_1.clone(), 9u64));
+		assert_ok!(PoeModule::trans_claim(Origin::signed(1), claim_2.clone(), 9u64));
+		assert_eq!(Balances::reserved_balance(1), 20);
+		assert_eq!(PoeModule::claim_deposits(&claim_1), Some((1, 10)));
+
+		assert_ok!(PoeModule::revoke_claim(Origin::signed(9), claim_1.clone()));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Balances::free_balance(1), 90);
+
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), claim_2.clone(), 0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 90);
+	})
+}
+
+// 9.4 测试押金用例：强制撤销时罚没押金
+#[test]
+fn test_claim_deposit_slashed_on_force_revoke() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let bounded_claim = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), bounded_claim.clone()));
+		let issuance = Balances::total_issuance();
+
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), bounded_claim.clone(), 7));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 90);
+		// Slashed为()，罚没的押金被销毁
+		assert_eq!(Balances::total_issuance(), issuance - 10);
+		System::assert_has_event(crate::Event::<Test>::DepositSlashed(1, 10).into());
+	})
+}
+
+// 9.5 测试押金用例：引入押金之前创建的存证没有押金，撤销和强制撤销都不会动用其他押金
+#[test]
+fn test_claim_without_deposit() {
+	new_test_ext().execute_with(|| {
+		let legacy = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![0,1]).unwrap();
+		let other = BoundedVec::<u8, <Test as Config>::MaxClaimLength>::try_from(vec![2,3]).unwrap();
+		Proofs::<Test>::insert(&legacy, (1, 0));
+		assert_ok!(PoeModule::create_claim(Origin::signed(1), other.clone()));
+
+		assert_ok!(PoeModule::force_revoke_claim(Origin::root(), legacy.clone(), 0));
+		assert_eq!(Balances::reserved_balance(1), 10);
+	})
+}
@@ -0,0 +1,172 @@
+//! Weights for pallet_poe
+//!
+//! Not generated yet: until `scripts/benchmark_weights.sh` has been run on the reference hardware,
+//! every extrinsic is charged the flat `10_000` used by the other pallets in this repo plus the
+//! storage it reads and writes. Claim length and batch size only count through those accesses.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use frame_support::sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_poe.
+pub trait WeightInfo {
+	fn create_claim(l: u32, ) -> Weight;
+	fn revoke_claim(l: u32, ) -> Weight;
+	fn trans_claim(l: u32, ) -> Weight;
+	fn add_delegate() -> Weight;
+	fn remove_delegate() -> Weight;
+	fn force_revoke_claim(l: u32, ) -> Weight;
+	fn set_anchor_signer() -> Weight;
+	fn submit_anchor(c: u32, ) -> Weight;
+	fn drop_anchor_batch() -> Weight;
+}
+
+/// Weights for pallet_poe charged by the runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule AnchorSigner (r:1 w:0)
+	// Storage: PoeModule NextAnchorBatch (r:1 w:1)
+	// Storage: PoeModule AnchorQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimDeposits (r:0 w:1)
+	fn create_claim(_l: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule Delegates (r:1 w:0)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ClaimDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn revoke_claim(_l: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule Delegates (r:1 w:0)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	fn trans_claim(_l: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Delegates (r:1 w:1)
+	fn add_delegate() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Delegates (r:1 w:1)
+	fn remove_delegate() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ClaimDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn force_revoke_claim(_l: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: PoeModule AnchorSigner (r:0 w:1)
+	fn set_anchor_signer() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule AnchorQueue (r:1 w:1)
+	// Storage: PoeModule Anchors (r:0 w:1)
+	fn submit_anchor(_c: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule AnchorQueue (r:1 w:1)
+	fn drop_anchor_batch() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule AnchorSigner (r:1 w:0)
+	// Storage: PoeModule NextAnchorBatch (r:1 w:1)
+	// Storage: PoeModule AnchorQueue (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: PoeModule ClaimDeposits (r:0 w:1)
+	fn create_claim(_l: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule Delegates (r:1 w:0)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ClaimDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn revoke_claim(_l: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule Delegates (r:1 w:0)
+	// Storage: PoeModule ClaimsOf (r:2 w:2)
+	fn trans_claim(_l: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: PoeModule Delegates (r:1 w:1)
+	fn add_delegate() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Delegates (r:1 w:1)
+	fn remove_delegate() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule Proofs (r:1 w:1)
+	// Storage: PoeModule ClaimsOf (r:1 w:1)
+	// Storage: PoeModule ClaimDeposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn force_revoke_claim(_l: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: PoeModule AnchorSigner (r:0 w:1)
+	fn set_anchor_signer() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: PoeModule AnchorQueue (r:1 w:1)
+	// Storage: PoeModule Anchors (r:0 w:1)
+	fn submit_anchor(_c: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: PoeModule AnchorQueue (r:1 w:1)
+	fn drop_anchor_batch() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -5,15 +5,69 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Template;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::{sp_std::vec, traits::{EnsureOrigin, Get}};
 use frame_system::RawOrigin;
 
+// The longest allowed counter name.
+fn counter_name<T: Config>() -> CounterName<T> {
+	vec![b'c'; T::MaxNameLength::get() as usize].try_into().expect("MaxNameLength bytes; qed")
+}
+
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	increment {
+		let caller: T::AccountId = whitelisted_caller();
+		let by = T::MaxValue::get() - T::MinValue::get();
+	}: _(RawOrigin::Signed(caller.clone()), by)
+	verify {
+		assert_eq!(Counters::<T>::get(&caller), Some(T::MaxValue::get()));
+	}
+
+	decrement {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		Counters::<T>::insert(&caller, T::MaxValue::get());
+		let by = T::MaxValue::get() - T::MinValue::get();
+	}: _(RawOrigin::Signed(caller.clone()), by)
+	verify {
+		assert_eq!(Counters::<T>::get(&caller), Some(T::MinValue::get()));
+	}
+
+	reset {
+		let caller: T::AccountId = whitelisted_caller();
+		Counters::<T>::insert(&caller, T::MaxValue::get());
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(Counters::<T>::get(&caller), None);
+	}
+
+	increment_global {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = counter_name::<T>();
+		let by = T::MaxValue::get() - T::MinValue::get();
+	}: _(RawOrigin::Signed(caller), name.clone(), by)
+	verify {
+		assert_eq!(GlobalCounters::<T>::get(&name), Some(T::MaxValue::get()));
+		assert_eq!(GlobalCounterCount::<T>::get(), 1);
+	}
+
+	decrement_global {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = counter_name::<T>();
+		GlobalCounters::<T>::insert(&name, T::MaxValue::get());
+		let by = T::MaxValue::get() - T::MinValue::get();
+	}: _(RawOrigin::Signed(caller), name.clone(), by)
+	verify {
+		assert_eq!(GlobalCounters::<T>::get(&name), Some(T::MinValue::get()));
+	}
+
+	reset_global {
+		let name = counter_name::<T>();
+		GlobalCounters::<T>::insert(&name, T::MaxValue::get());
+		GlobalCounterCount::<T>::put(1);
+		let origin = T::ResetOrigin::successful_origin();
+	}: _<T::Origin>(origin, name.clone())
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_eq!(GlobalCounters::<T>::get(&name), None);
+		assert_eq!(GlobalCounterCount::<T>::get(), 0);
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -14,47 +14,101 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// Name of a global counter.
+	pub type CounterName<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Lowest value a counter may take. New counters start here.
+		#[pallet::constant]
+		type MinValue: Get<u32>;
+
+		/// Highest value a counter may take.
+		#[pallet::constant]
+		type MaxValue: Get<u32>;
+
+		/// Maximum length in bytes of a global counter name.
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		/// Maximum number of global counters that may exist at once.
+		#[pallet::constant]
+		type MaxGlobalCounters: Get<u32>;
+
+		/// Origin allowed to reset global counters.
+		type ResetOrigin: EnsureOrigin<Self::Origin>;
 	}
 
+	/// v1 removed the old `Something` value and bounded the number of global counters.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	// The pallet's runtime storage items.
 	// https://docs.substrate.io/v3/runtime/storage
 	#[pallet::storage]
-	#[pallet::getter(fn something)]
+	#[pallet::getter(fn counter_of)]
 	// Learn more about declaring storage items:
 	// https://docs.substrate.io/v3/runtime/storage#declaring-storage-items
-	pub type Something<T> = StorageValue<_, u32>;
+	pub type Counters<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32>;
+
+	/// Named counters shared by every account.
+	#[pallet::storage]
+	#[pallet::getter(fn global_counter)]
+	pub type GlobalCounters<T: Config> = StorageMap<_, Blake2_128Concat, CounterName<T>, u32>;
+
+	/// Number of entries in `GlobalCounters`.
+	#[pallet::storage]
+	#[pallet::getter(fn global_counter_count)]
+	pub type GlobalCounterCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/v3/runtime/events-and-errors
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// Event documentation should end with an array that provides descriptive names for event
-		/// parameters. [something, who]
-		SomethingStored(u32, T::AccountId),
+		/// The counter of an account changed. [who, old, new]
+		CounterUpdated(T::AccountId, u32, u32),
+		/// The counter of an account was removed. [who, old]
+		CounterReset(T::AccountId, u32),
+		/// A global counter changed. [name, old, new]
+		GlobalCounterUpdated(CounterName<T>, u32, u32),
+		/// A global counter was removed by `ResetOrigin`. [name, old]
+		GlobalCounterReset(CounterName<T>, u32),
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
 	pub enum Error<T> {
-		/// Error names should be descriptive.
+		/// The counter has not been incremented yet.
 		NoneValue,
-		/// Errors should have helpful documentation associated with them.
+		/// The counter would go above `MaxValue`.
 		StorageOverflow,
+		/// The counter would go below `MinValue`.
+		StorageUnderflow,
+		/// `MaxGlobalCounters` global counters already exist.
+		TooManyGlobalCounters,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
 	}
 
 	// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -62,41 +116,94 @@ pub mod pallet {
 	// Dispatchable functions must be annotated with a weight and must return a DispatchResult.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// An example dispatchable that takes a singles value as a parameter, writes the value to
-		/// storage and emits an event. This function must be dispatched by a signed extrinsic.
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			// This function will return an error if the extrinsic is not signed.
-			// https://docs.substrate.io/v3/runtime/origins
+		/// Add `by` to the caller's counter, starting from `MinValue` if it does not exist.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		pub fn increment(origin: OriginFor<T>, by: u32) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			let (old, new) = Counters::<T>::try_mutate(&who, |value| Self::step(value, true, by))?;
+			Self::deposit_event(Event::CounterUpdated(who, old, new));
+			Ok(())
+		}
 
-			// Update storage.
-			<Something<T>>::put(something);
+		/// Subtract `by` from the caller's counter.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		pub fn decrement(origin: OriginFor<T>, by: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (old, new) = Counters::<T>::try_mutate(&who, |value| Self::step(value, false, by))?;
+			Self::deposit_event(Event::CounterUpdated(who, old, new));
+			Ok(())
+		}
+
+		/// Remove the caller's counter.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
+		pub fn reset(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let old = Counters::<T>::take(&who).ok_or(Error::<T>::NoneValue)?;
+			Self::deposit_event(Event::CounterReset(who, old));
+			Ok(())
+		}
 
-			// Emit an event.
-			Self::deposit_event(Event::SomethingStored(something, who));
-			// Return a successful DispatchResultWithPostInfo
+		/// Add `by` to the global counter `name`, starting from `MinValue` if it does not exist.
+		/// At most `MaxGlobalCounters` global counters can exist.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,2))]
+		pub fn increment_global(origin: OriginFor<T>, name: CounterName<T>, by: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+			let (old, new) = GlobalCounters::<T>::try_mutate(&name, |value| {
+				let created = value.is_none();
+				let changed = Self::step(value, true, by)?;
+				if created {
+					GlobalCounterCount::<T>::try_mutate(|count| -> Result<(), Error<T>> {
+						ensure!(*count < T::MaxGlobalCounters::get(), Error::<T>::TooManyGlobalCounters);
+						*count += 1;
+						Ok(())
+					})?;
+				}
+				Ok::<_, DispatchError>(changed)
+			})?;
+			Self::deposit_event(Event::GlobalCounterUpdated(name, old, new));
 			Ok(())
 		}
 
-		/// An example dispatchable that may throw a custom error.
+		/// Subtract `by` from the global counter `name`.
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1))]
-		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
-
-			// Read a value from storage.
-			match <Something<T>>::get() {
-				// Return an error if the value has not been set.
-				None => return Err(Error::<T>::NoneValue.into()),
-				Some(old) => {
-					// Increment the value read from storage; will error in the event of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-					// Update the value in storage with the incremented result.
-					<Something<T>>::put(new);
-					Ok(())
-				},
-			}
+		pub fn decrement_global(origin: OriginFor<T>, name: CounterName<T>, by: u32) -> DispatchResult {
+			ensure_signed(origin)?;
+			let (old, new) = GlobalCounters::<T>::try_mutate(&name, |value| Self::step(value, false, by))?;
+			Self::deposit_event(Event::GlobalCounterUpdated(name, old, new));
+			Ok(())
+		}
+
+		/// Remove the global counter `name`. Only `ResetOrigin` can do this.
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(2,2))]
+		pub fn reset_global(origin: OriginFor<T>, name: CounterName<T>) -> DispatchResult {
+			T::ResetOrigin::ensure_origin(origin)?;
+			let old = GlobalCounters::<T>::take(&name).ok_or(Error::<T>::NoneValue)?;
+			GlobalCounterCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			Self::deposit_event(Event::GlobalCounterReset(name, old));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Move `value` up or down by `by` within `[MinValue, MaxValue]`, returning the old and new
+		/// values. Only incrementing may create a counter.
+		fn step(value: &mut Option<u32>, up: bool, by: u32) -> Result<(u32, u32), DispatchError> {
+			let old = match (*value, up) {
+				(Some(old), _) => old,
+				(None, true) => T::MinValue::get(),
+				(None, false) => return Err(Error::<T>::NoneValue.into()),
+			};
+			let new = if up {
+				old.checked_add(by)
+					.filter(|new| *new <= T::MaxValue::get())
+					.ok_or(Error::<T>::StorageOverflow)?
+			} else {
+				old.checked_sub(by)
+					.filter(|new| *new >= T::MinValue::get())
+					.ok_or(Error::<T>::StorageUnderflow)?
+			};
+			*value = Some(new);
+			Ok((old, new))
 		}
 	}
 }
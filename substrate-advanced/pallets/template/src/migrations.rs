@@ -0,0 +1,31 @@
+//! Storage migrations for pallet-template
+
+use crate::{Config, GlobalCounterCount, GlobalCounters, Pallet};
+use frame_support::{
+	storage::{storage_prefix, unhashed},
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	/// v0 -> v1: kill the `Something: u32` value left behind by the old template and count the
+	/// existing global counters, which are now bounded by `MaxGlobalCounters`.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		unhashed::kill(&storage_prefix(Pallet::<T>::name().as_bytes(), b"Something"));
+
+		// Counters created before the bound keep working; only new ones are refused while the
+		// count is at or above `MaxGlobalCounters`.
+		let count = GlobalCounters::<T>::iter_keys().count() as Weight;
+		GlobalCounterCount::<T>::put(count as u32);
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(count + 1, 3)
+	}
+}
@@ -1,5 +1,5 @@
 use crate as pallet_template;
-use frame_support::traits::{ConstU16, ConstU64};
+use frame_support::traits::{ConstU16, ConstU32, ConstU64};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
@@ -51,9 +51,18 @@ impl system::Config for Test {
 
 impl pallet_template::Config for Test {
 	type Event = Event;
+	type MinValue = ConstU32<10>;
+	type MaxValue = ConstU32<20>;
+	type MaxNameLength = ConstU32<8>;
+	type MaxGlobalCounters = ConstU32<2>;
+	type ResetOrigin = frame_system::EnsureRoot<u64>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut ext: sp_io::TestExternalities =
+		system::GenesisConfig::default().build_storage::<Test>().unwrap().into();
+	// Events are not recorded in the genesis block.
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
@@ -1,20 +1,146 @@
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, CounterName, Error, Event as TemplateEvent, GlobalCounters};
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::{storage_prefix, unhashed},
+	traits::{GetStorageVersion, StorageVersion},
+};
+use sp_runtime::DispatchError;
+
+fn name(s: &str) -> CounterName<Test> {
+	s.as_bytes().to_vec().try_into().unwrap()
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn increment_starts_from_min_value() {
 	new_test_ext().execute_with(|| {
 		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(Origin::signed(1), 42));
+		assert_ok!(TemplateModule::increment(Origin::signed(1), 2));
 		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
+		assert_eq!(TemplateModule::counter_of(1), Some(12));
+		assert_eq!(TemplateModule::counter_of(2), None);
+		System::assert_last_event(TemplateEvent::CounterUpdated(1, 10, 12).into());
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn decrement_and_reset() {
 	new_test_ext().execute_with(|| {
 		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(TemplateModule::cause_error(Origin::signed(1)), Error::<Test>::NoneValue);
+		assert_noop!(TemplateModule::decrement(Origin::signed(1), 1), Error::<Test>::NoneValue);
+		assert_noop!(TemplateModule::reset(Origin::signed(1)), Error::<Test>::NoneValue);
+
+		assert_ok!(TemplateModule::increment(Origin::signed(1), 5));
+		assert_ok!(TemplateModule::decrement(Origin::signed(1), 3));
+		System::assert_last_event(TemplateEvent::CounterUpdated(1, 15, 12).into());
+
+		assert_ok!(TemplateModule::reset(Origin::signed(1)));
+		assert_eq!(TemplateModule::counter_of(1), None);
+		System::assert_last_event(TemplateEvent::CounterReset(1, 12).into());
+	});
+}
+
+#[test]
+fn counters_respect_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(TemplateModule::increment(Origin::signed(1), 11), Error::<Test>::StorageOverflow);
+		assert_noop!(TemplateModule::increment(Origin::signed(1), u32::MAX), Error::<Test>::StorageOverflow);
+		assert_ok!(TemplateModule::increment(Origin::signed(1), 10));
+		assert_noop!(TemplateModule::increment(Origin::signed(1), 1), Error::<Test>::StorageOverflow);
+
+		assert_noop!(TemplateModule::decrement(Origin::signed(1), 11), Error::<Test>::StorageUnderflow);
+		assert_noop!(TemplateModule::decrement(Origin::signed(1), 21), Error::<Test>::StorageUnderflow);
+		assert_ok!(TemplateModule::decrement(Origin::signed(1), 10));
+		assert_eq!(TemplateModule::counter_of(1), Some(10));
+	});
+}
+
+#[test]
+fn global_counters_are_shared() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::increment_global(Origin::signed(1), name("visits"), 1));
+		assert_ok!(TemplateModule::increment_global(Origin::signed(2), name("visits"), 2));
+		System::assert_last_event(TemplateEvent::GlobalCounterUpdated(name("visits"), 11, 13).into());
+		assert_ok!(TemplateModule::decrement_global(Origin::signed(3), name("visits"), 1));
+
+		assert_eq!(TemplateModule::global_counter(name("visits")), Some(12));
+		assert_eq!(TemplateModule::global_counter(name("other")), None);
+		assert_eq!(TemplateModule::counter_of(1), None);
+
+		assert_noop!(
+			TemplateModule::decrement_global(Origin::signed(1), name("other"), 1),
+			Error::<Test>::NoneValue
+		);
+		assert_noop!(
+			TemplateModule::increment_global(Origin::signed(1), name("visits"), 9),
+			Error::<Test>::StorageOverflow
+		);
+	});
+}
+
+#[test]
+fn only_root_resets_global_counters() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::increment_global(Origin::signed(1), name("visits"), 1));
+		assert_noop!(
+			TemplateModule::reset_global(Origin::signed(1), name("visits")),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(TemplateModule::reset_global(Origin::root(), name("visits")));
+		assert_eq!(TemplateModule::global_counter(name("visits")), None);
+		System::assert_last_event(TemplateEvent::GlobalCounterReset(name("visits"), 11).into());
+		assert_noop!(
+			TemplateModule::reset_global(Origin::root(), name("visits")),
+			Error::<Test>::NoneValue
+		);
+	});
+}
+
+#[test]
+fn global_counters_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::increment_global(Origin::signed(1), name("a"), 1));
+		assert_ok!(TemplateModule::increment_global(Origin::signed(1), name("b"), 1));
+		assert_eq!(TemplateModule::global_counter_count(), 2);
+
+		assert_noop!(
+			TemplateModule::increment_global(Origin::signed(1), name("c"), 1),
+			Error::<Test>::TooManyGlobalCounters
+		);
+		// Existing counters can still change.
+		assert_ok!(TemplateModule::increment_global(Origin::signed(2), name("a"), 1));
+
+		assert_ok!(TemplateModule::reset_global(Origin::root(), name("a")));
+		assert_eq!(TemplateModule::global_counter_count(), 1);
+		assert_ok!(TemplateModule::increment_global(Origin::signed(1), name("c"), 1));
+	});
+}
+
+#[test]
+fn migration_kills_something_and_counts_global_counters() {
+	new_test_ext().execute_with(|| {
+		let something = storage_prefix(b"TemplateModule", b"Something");
+		unhashed::put(&something, &42u32);
+		GlobalCounters::<Test>::insert(name("a"), 11);
+		GlobalCounters::<Test>::insert(name("b"), 12);
+		GlobalCounters::<Test>::insert(name("c"), 13);
+		StorageVersion::new(0).put::<TemplateModule>();
+
+		crate::migrations::v1::migrate::<Test>();
+
+		assert_eq!(unhashed::get::<u32>(&something), None);
+		assert_eq!(TemplateModule::global_counter_count(), 3);
+		assert_eq!(TemplateModule::on_chain_storage_version(), 1);
+		// Counters above the bound are kept but no new one can be created.
+		assert_eq!(TemplateModule::global_counter(name("c")), Some(13));
+		assert_noop!(
+			TemplateModule::increment_global(Origin::signed(1), name("d"), 1),
+			Error::<Test>::TooManyGlobalCounters
+		);
+
+		// Running it again is a no-op.
+		unhashed::put(&something, &42u32);
+		crate::migrations::v1::migrate::<Test>();
+		assert_eq!(unhashed::get::<u32>(&something), Some(42));
 	});
 }
@@ -0,0 +1,88 @@
+//! Chain extension giving ink! contracts access to `pallet_kitties`.
+//!
+//! The input of every function is the SCALE encoding of its arguments, the output is the SCALE
+//! encoding of its result:
+//!
+//! | func_id | input                            | output              |
+//! |---------|----------------------------------|---------------------|
+//! | 1       | `kitty_id: u32`                  | `Option<AccountId>` |
+//! | 2       | `kitty_id: u32`                  | `Option<[u8; 16]>`  |
+//! | 3       | `(kitty_id: u32, to: AccountId)` | nothing             |
+//! | 4       | `(kitty_id: u32, to: AccountId)` | nothing             |
+//!
+//! `3` calls `KittiesModule::transfor` signed by the contract that invoked the extension, so a
+//! contract can only move kitties it owns itself. `4` calls it signed by the caller of the
+//! contract instead, and fails unless the caller approved the contract with
+//! `KittiesModule::approve_operator` beforehand. Both go through `BaseCallFilter`, so a paused
+//! `transfor` stays paused for contracts too.
+
+use crate::{AccountId, Call, KittiesModule, Origin, Runtime};
+use codec::Encode;
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo},
+	traits::Get,
+};
+use pallet_contracts::chain_extension::{
+	ChainExtension, Environment, Ext, InitState, RetVal, SysConfig, UncheckedFrom,
+};
+use sp_runtime::DispatchError;
+
+/// Returns the owner of a kitty.
+pub const FUNC_KITTY_OWNER: u32 = 1;
+/// Returns the DNA of a kitty.
+pub const FUNC_KITTY_DNA: u32 = 2;
+/// Transfers a kitty owned by the calling contract.
+pub const FUNC_KITTY_TRANSFOR: u32 = 3;
+/// Transfers a kitty owned by the caller of the contract, if the caller approved the contract.
+pub const FUNC_KITTY_TRANSFOR_FROM_CALLER: u32 = 4;
+
+/// The chain extension used by `pallet_contracts` in this runtime.
+#[derive(Default)]
+pub struct KittiesExtension;
+
+impl ChainExtension<Runtime> for KittiesExtension {
+	fn call<E>(&mut self, env: Environment<E, InitState>) -> Result<RetVal, DispatchError>
+	where
+		E: Ext<T = Runtime>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		let mut env = env.buf_in_buf_out();
+		let read_weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+		match env.func_id() {
+			FUNC_KITTY_OWNER => {
+				let kitty_id: u32 = env.read_as()?;
+				env.charge_weight(read_weight)?;
+				let owner = KittiesModule::kitty_onwer(kitty_id);
+				env.write(&owner.encode(), false, None)?;
+			},
+			FUNC_KITTY_DNA => {
+				let kitty_id: u32 = env.read_as()?;
+				env.charge_weight(read_weight)?;
+				let dna = KittiesModule::kitties(kitty_id).map(|kitty| kitty.0);
+				env.write(&dna.encode(), false, None)?;
+			},
+			FUNC_KITTY_TRANSFOR => {
+				let (kitty_id, to): (u32, AccountId) = env.read_as()?;
+				let call = Call::KittiesModule(pallet_kitties::Call::transfor { kitty_id, to });
+				env.charge_weight(call.get_dispatch_info().weight)?;
+				let contract = env.ext().address().clone();
+				call.dispatch(Origin::signed(contract)).map_err(|e| e.error)?;
+			},
+			FUNC_KITTY_TRANSFOR_FROM_CALLER => {
+				let (kitty_id, to): (u32, AccountId) = env.read_as()?;
+				let call = Call::KittiesModule(pallet_kitties::Call::transfor { kitty_id, to });
+				env.charge_weight(read_weight.saturating_add(call.get_dispatch_info().weight))?;
+				let caller = env.ext().caller().clone();
+				let contract = env.ext().address().clone();
+				if !KittiesModule::is_operator(&caller, &contract) {
+					return Err(pallet_kitties::Error::<Runtime>::NotOperator.into())
+				}
+				call.dispatch(Origin::signed(caller)).map_err(|e| e.error)?;
+			},
+			_ => return Err(DispatchError::Other("KittiesExtension: unknown func_id")),
+		}
+
+		Ok(RetVal::Converging(0))
+	}
+}
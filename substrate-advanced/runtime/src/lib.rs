@@ -6,6 +6,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::{Decode, Encode, MaxEncodedLen};
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
 };
@@ -14,9 +15,12 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
+	traits::{
+		AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto, IdentifyAccount, NumberFor,
+		Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, FixedPointNumber, FixedU128, MultiSignature, Perquintill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -27,25 +31,33 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		tokens::BalanceConversion, ConstU128, ConstU16, ConstU32, ConstU64, ConstU8, Contains,
+		Currency, EitherOfDiverse, EqualPrivilegeOnly, Get, Imbalance, InstanceFilter,
+		KeyOwnerProofSystem, Nothing, OnUnbalanced, PalletInfoAccess, Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
-		IdentityFee, Weight,
+		ConstantMultiplier, IdentityFee, Weight, WeightToFeeCoefficient, WeightToFeeCoefficients,
+		WeightToFeePolynomial,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
-use pallet_transaction_payment::CurrencyAdapter;
+use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{Perbill, Percent, Permill};
+use smallvec::smallvec;
 
 /// Import the template pallet.
 pub use pallet_template;
 
+/// Lets ink! contracts read and transfer kitties.
+pub mod chain_extension;
+
 /// An index to a block.
 pub type BlockNumber = u32;
 
@@ -101,10 +113,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 115,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -125,6 +137,19 @@ pub const MINUTES: BlockNumber = 60_000 / (MILLISECS_PER_BLOCK as BlockNumber);
 pub const HOURS: BlockNumber = MINUTES * 60;
 pub const DAYS: BlockNumber = HOURS * 24;
 
+// Currency units. Deposits are expressed in these so they scale with the token.
+pub const MILLICENTS: Balance = 1_000_000_000;
+pub const CENTS: Balance = 1_000 * MILLICENTS;
+pub const DOLLARS: Balance = 100 * CENTS;
+
+/// Deposit for keeping `items` storage items of `bytes` total size on chain.
+pub const fn deposit(items: u32, bytes: u32) -> Balance {
+	items as Balance * 15 * CENTS + (bytes as Balance) * 6 * CENTS
+}
+
+/// Id of the in-game token created in the dev chain spec, used to pay fees for kitty actions.
+pub const GAME_ASSET_ID: u32 = 1;
+
 /// The version information used to identify this runtime when compiled natively.
 #[cfg(feature = "std")]
 pub fn native_version() -> NativeVersion {
@@ -144,11 +169,26 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
 }
 
+/// Pallets `Pause` refuses to pause and `BaseCallFilter` always lets through. `Timestamp`
+/// provides an inherent every block must include; `System`, `Sudo` and `Council` are needed to
+/// handle an incident without a runtime upgrade.
+pub struct UnpausablePallets;
+impl Contains<Vec<u8>> for UnpausablePallets {
+	fn contains(pallet_name: &Vec<u8>) -> bool {
+		[System::name(), Timestamp::name(), Sudo::name(), Council::name()]
+			.iter()
+			.any(|name| name.as_bytes() == pallet_name.as_slice())
+	}
+}
+
+/// Rejects calls paused through `Pause`, except those of `Pause` itself and `UnpausablePallets`.
+pub type BaseCallFilter = pallet_pause::NotPaused<Runtime>;
+
 // Configure FRAME pallets to include in runtime.
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = BaseCallFilter;
 	/// Block & extrinsics weights: base values and limits.
 	type BlockWeights = BlockWeights;
 	/// The maximum length of a block (in bytes).
@@ -242,19 +282,301 @@ impl pallet_balances::Config for Runtime {
 	type Balance = Balance;
 	/// The ubiquitous event type.
 	type Event = Event;
-	type DustRemoval = ();
+	type DustRemoval = ShareToTreasury<DustTreasuryShare>;
 	type ExistentialDeposit = ConstU128<500>;
 	type AccountStore = System;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const MinVestedTransfer: Balance = DOLLARS;
+}
+
+/// Vesting locks the unvested part of an account's free balance. The lock is a `Misc` lock in
+/// `pallet_balances`, so kitty reserves can only use the liquid and already vested funds, and a
+/// schedule's progress is only applied to the lock once `vest` is called.
+impl pallet_vesting::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
+}
+
+/// Maps weight to fee so that an extrinsic of `ExtrinsicBaseWeight` costs 1 CENT.
+pub struct WeightToFee;
+impl WeightToFeePolynomial for WeightToFee {
+	type Balance = Balance;
+	fn polynomial() -> WeightToFeeCoefficients<Self::Balance> {
+		let p = CENTS;
+		let q = Balance::from(ExtrinsicBaseWeight::get());
+		smallvec![WeightToFeeCoefficient {
+			degree: 1,
+			negative: false,
+			coeff_frac: Perbill::from_rational(p % q, q),
+			coeff_integer: p / q,
+		}]
+	}
+}
+
+parameter_types! {
+	pub const TransactionByteFee: Balance = 10 * MILLICENTS;
+	/// Blocks fuller than this raise the fee multiplier, emptier ones lower it.
+	pub const TargetBlockFullness: Perquintill = Perquintill::from_percent(25);
+	/// How fast the multiplier reacts to the block fullness.
+	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(3, 100_000);
+	/// Floor of 1e-9, as in the Substrate node: on an empty chain fees may fall to almost nothing,
+	/// and since each adjustment is multiplicative, climbing back from the floor takes many busy
+	/// blocks.
+	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000_000u128);
+}
+
 impl pallet_transaction_payment::Config for Runtime {
 	type Event = Event;
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = CurrencyAdapter<Balances, ShareToTreasury<FeeTreasuryShare>>;
 	type OperationalFeeMultiplier = ConstU8<5>;
-	type WeightToFee = IdentityFee<Balance>;
-	type LengthToFee = IdentityFee<Balance>;
-	type FeeMultiplierUpdate = ();
+	type WeightToFee = WeightToFee;
+	type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+	type FeeMultiplierUpdate =
+		TargetedFeeAdjustment<Self, TargetBlockFullness, AdjustmentVariable, MinimumMultiplier>;
+}
+
+parameter_types! {
+	pub const AssetDeposit: Balance = 100 * DOLLARS;
+	pub const AssetAccountDeposit: Balance = DOLLARS;
+	pub const ApprovalDeposit: Balance = DOLLARS;
+	pub const MetadataDepositBase: Balance = 10 * DOLLARS;
+	pub const MetadataDepositPerByte: Balance = DOLLARS;
+}
+
+impl pallet_assets::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type AssetId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	/// Game assets charged per unit of native fee. Root changes it with `System::set_storage`
+	/// under `GameAssetFeeRate::key()`; it is independent of the asset's `min_balance`.
+	pub storage GameAssetFeeRate: FixedU128 = FixedU128::saturating_from_integer(1u32);
+}
+
+/// Converts native fees into the game asset at `GameAssetFeeRate`. No other asset has a rate,
+/// so fees can only be paid natively or in the game asset.
+pub struct GameAssetFee;
+
+impl BalanceConversion<Balance, u32, Balance> for GameAssetFee {
+	type Error = pallet_assets::ConversionError;
+
+	fn to_asset_balance(balance: Balance, asset_id: u32) -> Result<Balance, Self::Error> {
+		if asset_id != GAME_ASSET_ID {
+			return Err(pallet_assets::ConversionError::AssetMissing)
+		}
+		Ok(GameAssetFeeRate::get().saturating_mul_int(balance))
+	}
+}
+
+/// Like native fees, the withdrawn assets are burned.
+impl pallet_asset_tx_payment::Config for Runtime {
+	type Fungibles = Assets;
+	type OnChargeAssetTransaction = pallet_asset_tx_payment::FungiblesAdapter<GameAssetFee, ()>;
+}
+
+impl pallet_utility::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	// One storage item; key size is 32 + 32; value is size 4 + 4 + 16 + 32 bytes = 120 bytes.
+	pub const DepositBase: Balance = deposit(1, 120);
+	// Additional storage item size of 32 bytes.
+	pub const DepositFactor: Balance = deposit(0, 32);
+}
+
+impl pallet_multisig::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type DepositBase = DepositBase;
+	type DepositFactor = DepositFactor;
+	type MaxSignatories = ConstU16<100>;
+	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
+}
+
+/// The kinds of calls a proxy account may make on behalf of its delegator.
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum ProxyType {
+	/// All calls.
+	Any,
+	/// Only `KittiesModule` calls, e.g. for a game bot.
+	Kitties,
+	/// Only `PoeModule` calls, e.g. for a notary bot.
+	Poe,
+	/// Only calls that cannot move balances, assets, kitties or claims to another account.
+	NonTransfer,
+}
+
+impl Default for ProxyType {
+	fn default() -> Self {
+		Self::Any
+	}
+}
+
+impl InstanceFilter<Call> for ProxyType {
+	fn filter(&self, c: &Call) -> bool {
+		match self {
+			ProxyType::Any => true,
+			// Calls nested in a `Utility` batch are filtered again against the same proxy type.
+			ProxyType::Kitties => matches!(c, Call::KittiesModule(..) | Call::Utility(..)),
+			ProxyType::Poe => matches!(c, Call::PoeModule(..) | Call::Utility(..)),
+			// An allowlist, so a pallet added later is not proxied until it is listed here.
+			ProxyType::NonTransfer => match c {
+				Call::System(..) |
+				Call::Grandpa(..) |
+				Call::TemplateModule(..) |
+				Call::Utility(..) |
+				Call::Multisig(..) |
+				Call::Council(..) |
+				Call::CouncilMembership(..) |
+				Call::Proxy(..) |
+				Call::Pause(..) |
+				Call::Identity(..) |
+				Call::Scheduler(..) |
+				Call::Preimage(..) |
+				Call::Treasury(..) => true,
+				Call::PoeModule(call) => matches!(
+					call,
+					pallet_poe::Call::create_claim { .. } |
+						pallet_poe::Call::revoke_claim { .. } |
+						pallet_poe::Call::add_delegate { .. } |
+						pallet_poe::Call::remove_delegate { .. }
+				),
+				Call::KittiesModule(call) => matches!(
+					call,
+					pallet_kitties::Call::create { .. } |
+						pallet_kitties::Call::breed { .. } |
+						pallet_kitties::Call::cancel_operator { .. }
+				),
+				Call::Assets(call) => matches!(
+					call,
+					pallet_assets::Call::create { .. } |
+						pallet_assets::Call::set_metadata { .. } |
+						pallet_assets::Call::clear_metadata { .. } |
+						pallet_assets::Call::freeze { .. } |
+						pallet_assets::Call::thaw { .. } |
+						pallet_assets::Call::freeze_asset { .. } |
+						pallet_assets::Call::thaw_asset { .. }
+				),
+				// Calling a contract, even without value, lets it act for the delegator.
+				Call::Contracts(call) => matches!(
+					call,
+					pallet_contracts::Call::upload_code { .. } |
+						pallet_contracts::Call::remove_code { .. }
+				),
+				Call::Vesting(call) => matches!(
+					call,
+					pallet_vesting::Call::vest { .. } | pallet_vesting::Call::vest_other { .. }
+				),
+				_ => false,
+			},
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			_ => false,
+		}
+	}
+}
+
+parameter_types! {
+	// One storage item; key size 32, value size 8.
+	pub const ProxyDepositBase: Balance = deposit(1, 8);
+	// Additional storage item size of 33 bytes.
+	pub const ProxyDepositFactor: Balance = deposit(0, 33);
+	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
+	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+}
+
+impl pallet_proxy::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyDepositBase;
+	type ProxyDepositFactor = ProxyDepositFactor;
+	type MaxProxies = ConstU32<32>;
+	type WeightInfo = pallet_proxy::weights::SubstrateWeight<Runtime>;
+	type MaxPending = ConstU32<32>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = AnnouncementDepositBase;
+	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+}
+
+parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = DAYS;
+	pub const CouncilMaxProposals: u32 = 100;
+	pub const CouncilMaxMembers: u32 = 100;
+}
+
+type CouncilCollective = pallet_collective::Instance1;
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type Origin = Origin;
+	type Proposal = Call;
+	type Event = Event;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = CouncilMaxProposals;
+	type MaxMembers = CouncilMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+}
+
+/// Admin origin of the custom pallets: root (sudo) or more than half of the council.
+pub type EnsureRootOrHalfCouncil = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+>;
+
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
+	type Event = Event;
+	type AddOrigin = EnsureRootOrHalfCouncil;
+	type RemoveOrigin = EnsureRootOrHalfCouncil;
+	type SwapOrigin = EnsureRootOrHalfCouncil;
+	type ResetOrigin = EnsureRootOrHalfCouncil;
+	type PrimeOrigin = EnsureRootOrHalfCouncil;
+	type MembershipInitialized = Council;
+	type MembershipChanged = Council;
+	type MaxMembers = CouncilMaxMembers;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_sudo::Config for Runtime {
@@ -265,11 +587,45 @@ impl pallet_sudo::Config for Runtime {
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type Event = Event;
+	type MinValue = ConstU32<0>;
+	type MaxValue = ConstU32<{ u32::MAX }>;
+	type MaxNameLength = ConstU32<32>;
+	type MaxGlobalCounters = ConstU32<1024>;
+	type ResetOrigin = EnsureRootOrHalfCouncil;
+}
+
+parameter_types! {
+	/// A claim is a 32 byte hash stored once in `Proofs` and once in `ClaimsOf`.
+	pub const PoeClaimDeposit: Balance = deposit(2, 64);
 }
 
 impl pallet_poe::Config for Runtime{
 	type Event = Event;
 	type MaxClaimLength = ConstU32<256>;
+	type MaxClaimsPerAccount = ConstU32<128>;
+	type MaxAnchorBatch = ConstU32<64>;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type ForceOrigin = EnsureRootOrHalfCouncil;
+	type Currency = Balances;
+	type ClaimDeposit = PoeClaimDeposit;
+	type Slashed = Treasury;
+	type WeightInfo = pallet_poe::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_pause::Config for Runtime {
+	type Event = Event;
+	type PauseOrigin = EnsureRootOrHalfCouncil;
+	type UnpausablePallets = UnpausablePallets;
+	type MaxNameLength = ConstU32<64>;
+	type WeightInfo = pallet_pause::weights::SubstrateWeight<Runtime>;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	Call: From<C>,
+{
+	type OverarchingCall = Call;
+	type Extrinsic = UncheckedExtrinsic;
 }
 
 impl pallet_kitties::Config for Runtime{
@@ -279,6 +635,176 @@ impl pallet_kitties::Config for Runtime{
 	type Currency = Balances;
 	type MaxKittyLength = ConstU32<64>;
 	type KittyPrice = ConstU128<64>;
+	type ForceOrigin = EnsureRootOrHalfCouncil;
+	type Slashed = Treasury;
+	type WeightInfo = pallet_kitties::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	// Minimum 100 bytes/DOLLAR deposited (1 CENT/byte).
+	pub const BasicDeposit: Balance = 10 * DOLLARS;
+	pub const FieldDeposit: Balance = 250 * CENTS;
+	pub const SubAccountDeposit: Balance = 2 * DOLLARS;
+}
+
+impl pallet_identity::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type BasicDeposit = BasicDeposit;
+	type FieldDeposit = FieldDeposit;
+	type SubAccountDeposit = SubAccountDeposit;
+	type MaxSubAccounts = ConstU32<100>;
+	type MaxAdditionalFields = ConstU32<100>;
+	type MaxRegistrars = ConstU32<20>;
+	type Slashed = Treasury;
+	type ForceOrigin = EnsureRootOrHalfCouncil;
+	type RegistrarOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_identity_registrars::Config for Runtime {}
+
+parameter_types! {
+	/// Scheduled calls may use up to 80% of a block.
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
+	/// Blocks to wait for a missing preimage before giving up on a scheduled call.
+	pub const NoPreimagePostponement: Option<BlockNumber> = Some(10);
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type Event = Event;
+	type Origin = Origin;
+	type PalletsOrigin = OriginCaller;
+	type Call = Call;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type MaxScheduledPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type PreimageProvider = Preimage;
+	type NoPreimagePostponement = NoPreimagePostponement;
+}
+
+parameter_types! {
+	pub const PreimageMaxSize: u32 = 4096 * 1024;
+	pub const PreimageBaseDeposit: Balance = DOLLARS;
+	pub const PreimageByteDeposit: Balance = CENTS;
+}
+
+impl pallet_preimage::Config for Runtime {
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Event = Event;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type MaxSize = PreimageMaxSize;
+	type BaseDeposit = PreimageBaseDeposit;
+	type ByteDeposit = PreimageByteDeposit;
+}
+
+parameter_types! {
+	pub const DepositPerItem: Balance = deposit(1, 0);
+	pub const DepositPerByte: Balance = deposit(0, 1);
+	pub const DeletionQueueDepth: u32 = 128;
+	/// Lazy deletion of terminated contracts may use up to 10% of a block.
+	pub DeletionWeightLimit: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub Schedule: pallet_contracts::Schedule<Runtime> = Default::default();
+}
+
+impl pallet_contracts::Config for Runtime {
+	type Time = Timestamp;
+	type Randomness = RandomnessCollectiveFlip;
+	type Currency = Balances;
+	type Event = Event;
+	type Call = Call;
+	/// Contracts can not dispatch runtime calls; kitties are reached through the chain extension.
+	type CallFilter = Nothing;
+	type DepositPerItem = DepositPerItem;
+	type DepositPerByte = DepositPerByte;
+	type CallStack = [pallet_contracts::Frame<Self>; 31];
+	type WeightPrice = pallet_transaction_payment::Pallet<Self>;
+	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Self>;
+	type ChainExtension = chain_extension::KittiesExtension;
+	type DeletionQueueDepth = DeletionQueueDepth;
+	type DeletionWeightLimit = DeletionWeightLimit;
+	type Schedule = Schedule;
+	type AddressGenerator = pallet_contracts::DefaultAddressGenerator;
+	type ContractAccessWeight = pallet_contracts::DefaultContractAccessWeight<BlockWeights>;
+	type MaxCodeLen = ConstU32<{ 128 * 1024 }>;
+	type RelaxedMaxCodeLen = ConstU32<{ 256 * 1024 }>;
+}
+
+type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// Sends a `Share` of an imbalance to the treasury and burns the rest. Used for transaction fees
+/// (including tips) and for dust removed from reaped accounts.
+pub struct ShareToTreasury<Share>(sp_std::marker::PhantomData<Share>);
+impl<Share: Get<Percent>> OnUnbalanced<NegativeImbalance> for ShareToTreasury<Share> {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+		let to_treasury = Share::get() * amount.peek();
+		let (to_treasury, _burned) = amount.split(to_treasury);
+		Treasury::on_unbalanced(to_treasury);
+	}
+}
+
+parameter_types! {
+	/// Share of transaction fees and tips paid into the treasury; the rest is burned.
+	pub const FeeTreasuryShare: Percent = Percent::from_percent(80);
+	/// Share of dust from reaped accounts paid into the treasury; the rest is burned.
+	pub const DustTreasuryShare: Percent = Percent::from_percent(100);
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = DOLLARS;
+	pub const ProposalBondMaximum: Balance = 100 * DOLLARS;
+	pub const SpendPeriod: BlockNumber = DAYS;
+	/// Unspent funds stay in the treasury.
+	pub const Burn: Permill = Permill::zero();
+	pub const MaxApprovals: u32 = 100;
+}
+
+/// Besides its share of fees and dust, the treasury receives the deposits slashed by moderation
+/// actions: `KittiesModule::force_remove`, `PoeModule::force_revoke_claim` and identity judgements.
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = EnsureRootOrHalfCouncil;
+	type RejectOrigin = EnsureRootOrHalfCouncil;
+	type Event = Event;
+	/// Slashed proposal bonds stay in the treasury.
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type ProposalBondMaximum = ProposalBondMaximum;
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxApprovals;
+}
+
+/// An account's identity as returned next to its kitties and claims by the runtime APIs.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct AccountProfile {
+	/// The raw display name, if the account set one.
+	pub display: Option<Vec<u8>>,
+	/// Judgements given by registrars, by registrar index.
+	pub judgements: Vec<(pallet_identity::RegistrarIndex, pallet_identity::Judgement<Balance>)>,
+}
+
+impl AccountProfile {
+	/// The profile of `who`, empty if it has no identity.
+	pub fn of(who: &AccountId) -> Self {
+		match Identity::identity(who) {
+			Some(registration) => Self {
+				display: match registration.info.display {
+					pallet_identity::Data::Raw(name) => Some(name.into_inner()),
+					_ => None,
+				},
+				judgements: registration.judgements.into_inner(),
+			},
+			None => Self { display: None, judgements: Vec::new() },
+		}
+	}
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -300,6 +826,21 @@ construct_runtime!(
 		TemplateModule: pallet_template,
 		PoeModule: pallet_poe,
 		KittiesModule: pallet_kitties,
+		Utility: pallet_utility,
+		Multisig: pallet_multisig,
+		Council: pallet_collective::<Instance1>,
+		CouncilMembership: pallet_membership::<Instance1>,
+		Proxy: pallet_proxy,
+		Pause: pallet_pause,
+		Assets: pallet_assets,
+		AssetTxPayment: pallet_asset_tx_payment,
+		Identity: pallet_identity,
+		IdentityRegistrars: pallet_identity_registrars,
+		Scheduler: pallet_scheduler,
+		Preimage: pallet_preimage,
+		Contracts: pallet_contracts,
+		Vesting: pallet_vesting,
+		Treasury: pallet_treasury,
 	}
 );
 
@@ -318,7 +859,7 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -333,6 +874,12 @@ pub type Executive = frame_executive::Executive<
 	AllPalletsWithSystem,
 >;
 
+/// Return the contracts debug buffer from the RPC dry-runs so ink! `debug_println!` is visible.
+const CONTRACTS_DEBUG_OUTPUT: bool = true;
+
+#[cfg(test)]
+mod tests;
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -345,6 +892,21 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_template, TemplateModule]
+		[pallet_poe, PoeModule]
+		[pallet_kitties, KittiesModule]
+		[pallet_utility, Utility]
+		[pallet_multisig, Multisig]
+		[pallet_collective, Council]
+		[pallet_membership, CouncilMembership]
+		[pallet_proxy, Proxy]
+		[pallet_pause, Pause]
+		[pallet_assets, Assets]
+		[pallet_identity, Identity]
+		[pallet_scheduler, Scheduler]
+		[pallet_preimage, Preimage]
+		[pallet_contracts, Contracts]
+		[pallet_vesting, Vesting]
+		[pallet_treasury, Treasury]
 	);
 }
 
@@ -479,6 +1041,87 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_poe_runtime_api::PoeApi<Block, AccountId, BlockNumber, AccountProfile> for Runtime {
+		fn claim(proof: Vec<u8>) -> Option<(AccountId, BlockNumber, AccountProfile)> {
+			let proof = proof.try_into().ok()?;
+			let (owner, block_number) = PoeModule::proofs(&proof)?;
+			let profile = AccountProfile::of(&owner);
+			Some((owner, block_number, profile))
+		}
+
+		fn claims_of(who: AccountId) -> Vec<Vec<u8>> {
+			PoeModule::claims_of(who).into_iter().map(|proof| proof.into_inner()).collect()
+		}
+	}
+
+	impl pallet_kitties_runtime_api::KittiesApi<Block, AccountId, u32, AccountProfile> for Runtime {
+		fn kitty(kitty_id: u32) -> Option<(AccountId, [u8; 16], AccountProfile)> {
+			let owner = KittiesModule::kitty_onwer(kitty_id)?;
+			let dna = KittiesModule::kitties(kitty_id)?.0;
+			let profile = AccountProfile::of(&owner);
+			Some((owner, dna, profile))
+		}
+	}
+
+	impl pallet_contracts_rpc_runtime_api::ContractsApi<Block, AccountId, Balance, BlockNumber, Hash>
+		for Runtime
+	{
+		fn call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: u64,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> pallet_contracts_primitives::ContractExecResult<Balance> {
+			Contracts::bare_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				CONTRACTS_DEBUG_OUTPUT,
+			)
+		}
+
+		fn instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: u64,
+			storage_deposit_limit: Option<Balance>,
+			code: pallet_contracts_primitives::Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> pallet_contracts_primitives::ContractInstantiateResult<AccountId, Balance> {
+			Contracts::bare_instantiate(
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data,
+				salt,
+				CONTRACTS_DEBUG_OUTPUT,
+			)
+		}
+
+		fn upload_code(
+			origin: AccountId,
+			code: Vec<u8>,
+			storage_deposit_limit: Option<Balance>,
+		) -> pallet_contracts_primitives::CodeUploadResult<Hash, Balance> {
+			Contracts::bare_upload_code(origin, code, storage_deposit_limit)
+		}
+
+		fn get_storage(
+			address: AccountId,
+			key: [u8; 32],
+		) -> pallet_contracts_primitives::GetStorageResult {
+			Contracts::get_storage(address, key)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
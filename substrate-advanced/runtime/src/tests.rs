@@ -0,0 +1,1152 @@
+//! Integration tests exercising several pallets through the real `Runtime` configuration.
+
+use crate::*;
+use codec::Encode;
+use frame_support::{
+	assert_ok,
+	traits::{GenesisBuild, WrapperKeepOpaque},
+	weights::GetDispatchInfo,
+	BoundedVec,
+};
+use sp_core::crypto::AccountId32;
+use sp_runtime::traits::Hash;
+
+pub(crate) fn alice() -> AccountId {
+	AccountId32::new([1u8; 32])
+}
+
+pub(crate) fn bob() -> AccountId {
+	AccountId32::new([2u8; 32])
+}
+
+pub(crate) fn charlie() -> AccountId {
+	AccountId32::new([3u8; 32])
+}
+
+/// Genesis shared by all tests: Alice and Bob hold 100 DOLLARS each and Alice, Bob and Charlie
+/// sit on the council. Tests that need more genesis state add it here instead of building their
+/// own storage.
+#[derive(Default)]
+pub(crate) struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+	registrars: Vec<(AccountId, Balance)>,
+	vesting: Vec<(AccountId, BlockNumber, BlockNumber, Balance)>,
+}
+
+impl ExtBuilder {
+	/// Endows `who` with `amount` on top of the default balances.
+	pub(crate) fn balance(mut self, who: AccountId, amount: Balance) -> Self {
+		self.balances.push((who, amount));
+		self
+	}
+
+	/// Registers `who` as an identity registrar charging `fee`, in the order of the calls.
+	pub(crate) fn registrar(mut self, who: AccountId, fee: Balance) -> Self {
+		self.registrars.push((who, fee));
+		self
+	}
+
+	/// Locks the balance of `who` above `liquid`, vesting linearly over `length` blocks from
+	/// `begin`.
+	pub(crate) fn vesting(
+		mut self,
+		who: AccountId,
+		begin: BlockNumber,
+		length: BlockNumber,
+		liquid: Balance,
+	) -> Self {
+		self.vesting.push((who, begin, length, liquid));
+		self
+	}
+
+	pub(crate) fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
+		let mut balances = vec![(alice(), 100 * DOLLARS), (bob(), 100 * DOLLARS)];
+		balances.extend(self.balances);
+		pallet_balances::GenesisConfig::<Runtime> { balances }.assimilate_storage(&mut t).unwrap();
+		pallet_membership::GenesisConfig::<Runtime, pallet_membership::Instance1> {
+			members: vec![alice(), bob(), charlie()].try_into().unwrap(),
+			phantom: Default::default(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		pallet_identity_registrars::GenesisConfig::<Runtime> { registrars: self.registrars }
+			.assimilate_storage(&mut t)
+			.unwrap();
+		pallet_vesting::GenesisConfig::<Runtime> { vesting: self.vesting }
+			.assimilate_storage(&mut t)
+			.unwrap();
+		let mut ext = sp_io::TestExternalities::new(t);
+		// Events are not recorded in the genesis block.
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default().build()
+}
+
+pub(crate) fn claim(data: &[u8]) -> BoundedVec<u8, ConstU32<256>> {
+	PoeModule::claim_of(data).unwrap()
+}
+
+fn create_kitty() -> Call {
+	Call::KittiesModule(pallet_kitties::Call::create {})
+}
+
+fn create_claim(data: &[u8]) -> Call {
+	Call::PoeModule(pallet_poe::Call::create_claim { proof: claim(data) })
+}
+
+// 1. pallet_utility
+mod utility {
+	use super::*;
+
+	#[test]
+	fn batch_all_creates_kitty_and_claim() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Utility::batch_all(
+				Origin::signed(alice()),
+				vec![create_kitty(), create_claim(b"hello")]
+			));
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(alice()));
+			assert_eq!(PoeModule::claims_of(alice()).into_inner(), vec![claim(b"hello")]);
+		});
+	}
+
+	#[test]
+	fn batch_all_reverts_everything_on_failure() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(PoeModule::create_claim(Origin::signed(bob()), claim(b"hello")));
+			assert!(Utility::batch_all(
+				Origin::signed(alice()),
+				vec![create_kitty(), create_claim(b"hello")]
+			)
+			.is_err());
+			assert_eq!(KittiesModule::kitty_onwer(0), None);
+			assert_eq!(Balances::reserved_balance(alice()), 0);
+		});
+	}
+
+	#[test]
+	fn batch_keeps_calls_before_the_failure() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(PoeModule::create_claim(Origin::signed(bob()), claim(b"hello")));
+			assert_ok!(Utility::batch(
+				Origin::signed(alice()),
+				vec![create_kitty(), create_claim(b"hello"), create_claim(b"world")]
+			));
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(alice()));
+			assert!(PoeModule::claims_of(alice()).is_empty());
+			assert!(System::events().iter().any(|record| matches!(
+				record.event,
+				Event::Utility(pallet_utility::Event::BatchInterrupted { index: 1, .. })
+			)));
+		});
+	}
+
+	#[test]
+	fn as_derivative_acts_for_a_sub_account() {
+		new_test_ext().execute_with(|| {
+			let derived = pallet_utility::Pallet::<Runtime>::derivative_account_id(alice(), 0);
+			assert_ok!(Balances::transfer(Origin::signed(alice()), derived.clone().into(), DOLLARS));
+			assert_ok!(Utility::as_derivative(Origin::signed(alice()), 0, Box::new(create_claim(b"sub"))));
+			assert_eq!(PoeModule::claims_of(derived).into_inner(), vec![claim(b"sub")]);
+		});
+	}
+}
+
+// 2. pallet_multisig
+mod multisig {
+	use super::*;
+
+	#[test]
+	fn two_of_two_multisig_creates_a_kitty() {
+		new_test_ext().execute_with(|| {
+			let multi = Multisig::multi_account_id(&[alice(), bob()], 2);
+			assert_ok!(Balances::transfer(Origin::signed(alice()), multi.clone().into(), 10 * DOLLARS));
+
+			let call = create_kitty();
+			let call_hash = sp_core::blake2_256(&call.encode());
+			let call_weight = call.get_dispatch_info().weight;
+			assert_ok!(Multisig::approve_as_multi(
+				Origin::signed(alice()),
+				2,
+				vec![bob()],
+				None,
+				call_hash,
+				0
+			));
+			assert_eq!(Balances::reserved_balance(alice()), DepositBase::get() + 2 * DepositFactor::get());
+
+			let timepoint = Multisig::timepoint();
+			assert_ok!(Multisig::as_multi(
+				Origin::signed(bob()),
+				2,
+				vec![alice()],
+				Some(timepoint),
+				WrapperKeepOpaque::from_encoded(call.encode()),
+				false,
+				call_weight
+			));
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(multi));
+			assert_eq!(Balances::reserved_balance(alice()), 0);
+		});
+	}
+}
+
+// 3. pallet_collective / pallet_membership
+mod council {
+	use super::*;
+
+	fn force_revoke(data: &[u8]) -> Call {
+		Call::PoeModule(pallet_poe::Call::force_revoke_claim { proof: claim(data), reason: 1 })
+	}
+
+	#[test]
+	fn genesis_membership_seeds_the_council() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(Council::members(), vec![alice(), bob(), charlie()]);
+		});
+	}
+
+	#[test]
+	fn council_majority_can_force_revoke_a_claim() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(PoeModule::create_claim(Origin::signed(bob()), claim(b"fraud")));
+
+			let proposal = force_revoke(b"fraud");
+			let length = proposal.encoded_size() as u32;
+			let weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Council::propose(Origin::signed(alice()), 2, Box::new(proposal), length));
+			assert_ok!(Council::vote(Origin::signed(alice()), hash, 0, true));
+			assert_ok!(Council::vote(Origin::signed(charlie()), hash, 0, true));
+			assert_ok!(Council::close(Origin::signed(alice()), hash, 0, weight, length));
+
+			assert!(PoeModule::claims_of(bob()).is_empty());
+		});
+	}
+
+	#[test]
+	fn single_council_member_cannot_force_revoke() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(PoeModule::create_claim(Origin::signed(bob()), claim(b"fraud")));
+
+			// 阈值为1的提案立即以1/3的议会身份执行，不满足过半数要求
+			let proposal = force_revoke(b"fraud");
+			let length = proposal.encoded_size() as u32;
+			assert_ok!(Council::propose(Origin::signed(alice()), 1, Box::new(proposal), length));
+
+			assert_eq!(PoeModule::claims_of(bob()).into_inner(), vec![claim(b"fraud")]);
+		});
+	}
+
+	#[test]
+	fn council_majority_can_add_a_member() {
+		new_test_ext().execute_with(|| {
+			let dave = AccountId32::new([4u8; 32]);
+			let proposal = Call::CouncilMembership(pallet_membership::Call::add_member { who: dave.clone() });
+			let length = proposal.encoded_size() as u32;
+			let weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Council::propose(Origin::signed(bob()), 2, Box::new(proposal), length));
+			assert_ok!(Council::vote(Origin::signed(bob()), hash, 0, true));
+			assert_ok!(Council::vote(Origin::signed(charlie()), hash, 0, true));
+			assert_ok!(Council::close(Origin::signed(bob()), hash, 0, weight, length));
+
+			assert!(Council::members().contains(&dave));
+		});
+	}
+}
+
+// 4. pallet_proxy
+mod proxy {
+	use super::*;
+	use sp_runtime::DispatchError;
+
+	fn proxy_result() -> Option<Result<(), DispatchError>> {
+		System::events().into_iter().rev().find_map(|r| match r.event {
+			Event::Proxy(pallet_proxy::Event::ProxyExecuted { result }) => Some(result),
+			_ => None,
+		})
+	}
+
+	fn call_filtered() -> Option<Result<(), DispatchError>> {
+		Some(Err(frame_system::Error::<Runtime>::CallFiltered.into()))
+	}
+
+	fn transfer_to_bob() -> Call {
+		Call::Balances(pallet_balances::Call::transfer { dest: bob().into(), value: DOLLARS })
+	}
+
+	#[test]
+	fn kitties_proxy_creates_kitty_for_delegator() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Proxy::add_proxy(Origin::signed(alice()), bob(), ProxyType::Kitties, 0));
+			assert_ok!(Proxy::proxy(Origin::signed(bob()), alice(), None, Box::new(create_kitty())));
+			assert_eq!(proxy_result(), Some(Ok(())));
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(alice()));
+		});
+	}
+
+	#[test]
+	fn kitties_proxy_rejects_poe_and_balance_calls() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Proxy::add_proxy(Origin::signed(alice()), bob(), ProxyType::Kitties, 0));
+
+			assert_ok!(Proxy::proxy(Origin::signed(bob()), alice(), None, Box::new(create_claim(b"hello"))));
+			assert_eq!(proxy_result(), call_filtered());
+			assert!(PoeModule::claims_of(alice()).is_empty());
+
+			assert_ok!(Proxy::proxy(Origin::signed(bob()), alice(), None, Box::new(transfer_to_bob())));
+			assert_eq!(proxy_result(), call_filtered());
+		});
+	}
+
+	#[test]
+	fn poe_proxy_filters_calls_nested_in_a_batch() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Proxy::add_proxy(Origin::signed(alice()), bob(), ProxyType::Poe, 0));
+
+			assert_ok!(Proxy::proxy(Origin::signed(bob()), alice(), None, Box::new(create_claim(b"hello"))));
+			assert_eq!(proxy_result(), Some(Ok(())));
+			assert_eq!(PoeModule::claims_of(alice()).into_inner(), vec![claim(b"hello")]);
+
+			// `batch_all` passes the filter itself, but the kitty call inside it does not.
+			let batch = Call::Utility(pallet_utility::Call::batch_all {
+				calls: vec![create_claim(b"world"), create_kitty()],
+			});
+			assert_ok!(Proxy::proxy(Origin::signed(bob()), alice(), None, Box::new(batch)));
+			assert!(proxy_result().unwrap().is_err());
+			assert_eq!(KittiesModule::kitty_onwer(0), None);
+			assert_eq!(PoeModule::claims_of(alice()).into_inner(), vec![claim(b"hello")]);
+		});
+	}
+
+	#[test]
+	fn non_transfer_proxy_rejects_transfers() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Proxy::add_proxy(Origin::signed(alice()), bob(), ProxyType::NonTransfer, 0));
+
+			assert_ok!(Proxy::proxy(Origin::signed(bob()), alice(), None, Box::new(create_kitty())));
+			assert_eq!(proxy_result(), Some(Ok(())));
+
+			let transfer_kitty =
+				Call::KittiesModule(pallet_kitties::Call::transfor { kitty_id: 0, to: bob() });
+			assert_ok!(Proxy::proxy(Origin::signed(bob()), alice(), None, Box::new(transfer_kitty)));
+			assert_eq!(proxy_result(), call_filtered());
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(alice()));
+
+			assert_ok!(Proxy::proxy(Origin::signed(bob()), alice(), None, Box::new(transfer_to_bob())));
+			assert_eq!(proxy_result(), call_filtered());
+		});
+	}
+
+	#[test]
+	fn non_transfer_proxy_only_allows_listed_calls() {
+		use frame_support::traits::InstanceFilter;
+
+		let assets_transfer = Call::Assets(pallet_assets::Call::transfer { id: 1, target: bob().into(), amount: 1 });
+		let assets_keep_alive =
+			Call::Assets(pallet_assets::Call::transfer_keep_alive { id: 1, target: bob().into(), amount: 1 });
+		let vested_transfer = Call::Vesting(pallet_vesting::Call::vested_transfer {
+			target: bob().into(),
+			schedule: pallet_vesting::VestingInfo::new(10 * DOLLARS, DOLLARS, 0),
+		});
+		let contract_call = Call::Contracts(pallet_contracts::Call::call {
+			dest: bob().into(),
+			value: DOLLARS,
+			gas_limit: 0,
+			storage_deposit_limit: None,
+			data: vec![],
+		});
+		let trans_claim = Call::PoeModule(pallet_poe::Call::trans_claim { proof: claim(b"hello"), receiver: bob() });
+		let approve_operator = Call::KittiesModule(pallet_kitties::Call::approve_operator { operator: bob() });
+		for call in [
+			transfer_to_bob(),
+			assets_transfer,
+			assets_keep_alive,
+			vested_transfer,
+			contract_call,
+			trans_claim,
+			approve_operator,
+		] {
+			assert!(!ProxyType::NonTransfer.filter(&call), "{:?} must not pass NonTransfer", call);
+		}
+
+		let vest = Call::Vesting(pallet_vesting::Call::vest {});
+		let cancel_operator = Call::KittiesModule(pallet_kitties::Call::cancel_operator { operator: bob() });
+		for call in [create_kitty(), create_claim(b"hello"), vest, cancel_operator] {
+			assert!(ProxyType::NonTransfer.filter(&call), "{:?} must pass NonTransfer", call);
+		}
+	}
+
+	#[test]
+	fn any_proxy_is_a_superset() {
+		assert!(ProxyType::Any.is_superset(&ProxyType::Kitties));
+		assert!(ProxyType::Poe.is_superset(&ProxyType::Poe));
+		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Kitties));
+		assert!(!ProxyType::Kitties.is_superset(&ProxyType::Any));
+	}
+}
+
+// 5. pallet_pause as the BaseCallFilter
+mod pause {
+	use super::*;
+	use frame_support::{assert_noop, dispatch::Dispatchable};
+	use sp_runtime::DispatchError;
+
+	fn call_filtered() -> DispatchError {
+		frame_system::Error::<Runtime>::CallFiltered.into()
+	}
+
+	#[test]
+	fn paused_kitty_trading_is_rejected_until_unpaused() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(KittiesModule::create(Origin::signed(alice())));
+			assert_ok!(Pause::pause(Origin::root(), b"KittiesModule".to_vec(), Some(b"transfor".to_vec())));
+
+			let transfer = Call::KittiesModule(pallet_kitties::Call::transfor { kitty_id: 0, to: bob() });
+			assert_noop!(
+				transfer.clone().dispatch(Origin::signed(alice())).map_err(|e| e.error),
+				call_filtered()
+			);
+			// 同一pallet中未暂停的调用不受影响
+			assert_ok!(create_kitty().dispatch(Origin::signed(alice())));
+
+			assert_ok!(Pause::unpause(Origin::root(), b"KittiesModule".to_vec(), Some(b"transfor".to_vec())));
+			assert_ok!(transfer.dispatch(Origin::signed(alice())));
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(bob()));
+		});
+	}
+
+	#[test]
+	fn paused_pallet_rejects_calls_nested_in_a_batch() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pause::pause(Origin::root(), b"PoeModule".to_vec(), None));
+
+			assert_noop!(
+				create_claim(b"hello").dispatch(Origin::signed(alice())).map_err(|e| e.error),
+				call_filtered()
+			);
+			let batch = Call::Utility(pallet_utility::Call::batch_all { calls: vec![create_claim(b"hello")] });
+			assert!(batch.dispatch(Origin::signed(alice())).is_err());
+			assert!(PoeModule::claims_of(alice()).is_empty());
+		});
+	}
+
+	#[test]
+	fn unpausable_pallets_are_always_callable() {
+		new_test_ext().execute_with(|| {
+			for pallet in [&b"System"[..], b"Timestamp", b"Sudo", b"Council", b"Pause"] {
+				assert_noop!(
+					Pause::pause(Origin::root(), pallet.to_vec(), None),
+					pallet_pause::Error::<Runtime>::Unpausable
+				);
+			}
+
+			let remark = Call::System(frame_system::Call::remark { remark: vec![1] });
+			assert!(BaseCallFilter::contains(&remark));
+			let set_timestamp = Call::Timestamp(pallet_timestamp::Call::set { now: 0 });
+			assert!(BaseCallFilter::contains(&set_timestamp));
+			let disapprove =
+				Call::Council(pallet_collective::Call::disapprove_proposal { proposal_hash: Default::default() });
+			assert!(BaseCallFilter::contains(&disapprove));
+			let sudo = Call::Sudo(pallet_sudo::Call::sudo { call: Box::new(remark) });
+			assert!(BaseCallFilter::contains(&sudo));
+			let unpause =
+				Call::Pause(pallet_pause::Call::unpause { pallet_name: b"System".to_vec(), call_name: None });
+			assert!(BaseCallFilter::contains(&unpause));
+		});
+	}
+}
+
+// 6. pallet_transaction_payment: congestion-responsive fees
+mod fees {
+	use super::*;
+	use frame_support::{
+		traits::OnFinalize,
+		weights::{DispatchClass, WeightToFee as _},
+	};
+
+	// Fee of creating a kitty with a 100 byte extrinsic, including the current multiplier.
+	fn kitty_fee() -> Balance {
+		let info = create_kitty().get_dispatch_info();
+		TransactionPayment::compute_fee(100, &info, 0)
+	}
+
+	// Finalize `blocks` blocks, each with `weight` of normal extrinsics.
+	fn run_blocks(blocks: u32, weight: Weight) {
+		for _ in 0..blocks {
+			let n = System::block_number();
+			System::set_block_consumed_resources(weight, 0);
+			TransactionPayment::on_finalize(n);
+			System::set_block_number(n + 1);
+		}
+	}
+
+	fn full_block() -> Weight {
+		BlockWeights::get().get(DispatchClass::Normal).max_total.unwrap()
+	}
+
+	#[test]
+	fn base_extrinsic_costs_about_one_cent() {
+		let fee = WeightToFee::weight_to_fee(&ExtrinsicBaseWeight::get());
+		assert!(fee.max(CENTS) - fee.min(CENTS) < MILLICENTS);
+	}
+
+	#[test]
+	fn length_is_charged_per_byte() {
+		new_test_ext().execute_with(|| {
+			let info = create_kitty().get_dispatch_info();
+			let short = TransactionPayment::compute_fee(100, &info, 0);
+			let long = TransactionPayment::compute_fee(1_100, &info, 0);
+			assert_eq!(long - short, 1_000 * TransactionByteFee::get());
+		});
+	}
+
+	#[test]
+	fn fees_rise_under_full_blocks_and_recover() {
+		new_test_ext().execute_with(|| {
+			let initial = kitty_fee();
+			assert_eq!(TransactionPayment::next_fee_multiplier(), Multiplier::one());
+
+			run_blocks(100, full_block());
+			let congested = kitty_fee();
+			assert!(TransactionPayment::next_fee_multiplier() > Multiplier::one());
+			assert!(congested > initial);
+
+			// Once congestion is over, empty blocks bring the fee back down.
+			run_blocks(1_000, 0);
+			assert!(TransactionPayment::next_fee_multiplier() < Multiplier::one());
+			assert!(kitty_fee() < initial);
+		});
+	}
+
+	#[test]
+	fn multiplier_never_drops_below_the_minimum() {
+		new_test_ext().execute_with(|| {
+			pallet_transaction_payment::NextFeeMultiplier::<Runtime>::put(MinimumMultiplier::get());
+			run_blocks(10, 0);
+			assert_eq!(TransactionPayment::next_fee_multiplier(), MinimumMultiplier::get());
+		});
+	}
+}
+
+// 7. pallet_assets / pallet_asset_tx_payment: fees paid in the game asset
+mod asset_fees {
+	use super::*;
+	use frame_support::{
+		dispatch::DispatchResultWithPostInfo,
+		weights::{DispatchInfo, Pays, PostDispatchInfo},
+	};
+	use pallet_asset_tx_payment::ChargeAssetTxPayment;
+	use sp_runtime::{traits::SignedExtension, FixedPointNumber, FixedU128};
+
+	const MINTED: Balance = 10 * DOLLARS;
+
+	fn new_asset_ext(min_balance: Balance) -> sp_io::TestExternalities {
+		let mut ext = new_test_ext();
+		ext.execute_with(|| {
+			assert_ok!(Assets::force_create(Origin::root(), GAME_ASSET_ID, alice().into(), true, min_balance));
+			assert_ok!(Assets::mint(Origin::signed(alice()), GAME_ASSET_ID, bob().into(), MINTED));
+		});
+		ext
+	}
+
+	// Charge `who` for creating a kitty, paying in `asset` if given, and return the native fee.
+	fn charge(who: &AccountId, asset: Option<u32>) -> Balance {
+		let call = create_kitty();
+		let info: DispatchInfo = call.get_dispatch_info();
+		let len = 100;
+		let pre = ChargeAssetTxPayment::<Runtime>::from(0, asset)
+			.pre_dispatch(who, &call, &info, len)
+			.unwrap();
+		let post_info = PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes };
+		assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(Some(pre), &info, &post_info, len, &Ok(())));
+		TransactionPayment::compute_fee(len as u32, &info, 0)
+	}
+
+	#[test]
+	fn fee_is_paid_in_the_game_asset() {
+		new_asset_ext(500).execute_with(|| {
+			let native = Balances::free_balance(bob());
+			let fee = charge(&bob(), Some(GAME_ASSET_ID));
+
+			// The default rate is one to one.
+			assert_eq!(Assets::balance(GAME_ASSET_ID, bob()), MINTED - fee);
+			assert_eq!(Balances::free_balance(bob()), native);
+		});
+	}
+
+	#[test]
+	fn fee_is_paid_natively_without_an_asset() {
+		new_asset_ext(500).execute_with(|| {
+			let native = Balances::free_balance(bob());
+			let fee = charge(&bob(), None);
+
+			assert_eq!(Assets::balance(GAME_ASSET_ID, bob()), MINTED);
+			assert_eq!(Balances::free_balance(bob()), native - fee);
+		});
+	}
+
+	fn set_rate(origin: Origin, rate: FixedU128) -> DispatchResultWithPostInfo {
+		System::set_storage(origin, vec![(GameAssetFeeRate::key().to_vec(), rate.encode())])
+	}
+
+	#[test]
+	fn root_sets_the_conversion_rate() {
+		new_asset_ext(500).execute_with(|| {
+			assert_ok!(set_rate(Origin::root(), FixedU128::saturating_from_integer(2u32)));
+			let fee = charge(&bob(), Some(GAME_ASSET_ID));
+			assert_eq!(Assets::balance(GAME_ASSET_ID, bob()), MINTED - 2 * fee);
+		});
+	}
+
+	#[test]
+	fn conversion_rate_cannot_be_set_by_a_signed_account() {
+		new_asset_ext(500).execute_with(|| {
+			assert!(set_rate(Origin::signed(alice()), FixedU128::saturating_from_integer(2u32)).is_err());
+			assert_eq!(GameAssetFeeRate::get(), FixedU128::saturating_from_integer(1u32));
+		});
+	}
+
+	#[test]
+	fn conversion_rate_does_not_follow_min_balance() {
+		new_asset_ext(500).execute_with(|| {
+			assert_ok!(Assets::force_asset_status(
+				Origin::root(),
+				GAME_ASSET_ID,
+				alice().into(),
+				alice().into(),
+				alice().into(),
+				alice().into(),
+				1_000,
+				true,
+				false,
+			));
+			let fee = charge(&bob(), Some(GAME_ASSET_ID));
+			assert_eq!(Assets::balance(GAME_ASSET_ID, bob()), MINTED - fee);
+		});
+	}
+
+	#[test]
+	fn fee_cannot_be_paid_in_another_asset() {
+		new_asset_ext(500).execute_with(|| {
+			let other = GAME_ASSET_ID + 1;
+			assert_ok!(Assets::force_create(Origin::root(), other, alice().into(), true, 500));
+			assert_ok!(Assets::mint(Origin::signed(alice()), other, bob().into(), MINTED));
+
+			let call = create_kitty();
+			let info = call.get_dispatch_info();
+			assert!(ChargeAssetTxPayment::<Runtime>::from(0, Some(other))
+				.pre_dispatch(&bob(), &call, &info, 100)
+				.is_err());
+			assert_eq!(Assets::balance(other, bob()), MINTED);
+		});
+	}
+}
+
+// 8. pallet_identity: profiles in the kitties and PoE runtime APIs
+mod identity {
+	use super::*;
+	use pallet_identity::{Data, IdentityInfo, Judgement};
+
+	fn new_identity_ext() -> sp_io::TestExternalities {
+		ExtBuilder::default().registrar(charlie(), 0).build()
+	}
+
+	fn set_display(who: AccountId, name: &[u8]) {
+		let info = IdentityInfo {
+			additional: Default::default(),
+			display: Data::Raw(name.to_vec().try_into().unwrap()),
+			legal: Data::None,
+			web: Data::None,
+			riot: Data::None,
+			email: Data::None,
+			pgp_fingerprint: None,
+			image: Data::None,
+			twitter: Data::None,
+		};
+		assert_ok!(Identity::set_identity(Origin::signed(who), Box::new(info)));
+	}
+
+	#[test]
+	fn genesis_registrar_can_judge() {
+		new_identity_ext().execute_with(|| {
+			let registrar = Identity::registrars()[0].clone().unwrap();
+			assert_eq!((registrar.account, registrar.fee), (charlie(), 0));
+
+			set_display(bob(), b"bob the breeder");
+			assert_ok!(Identity::request_judgement(Origin::signed(bob()), 0, 0));
+			assert_ok!(Identity::provide_judgement(
+				Origin::signed(charlie()),
+				0,
+				bob().into(),
+				Judgement::Reasonable
+			));
+
+			assert_eq!(
+				AccountProfile::of(&bob()),
+				AccountProfile {
+					display: Some(b"bob the breeder".to_vec()),
+					judgements: vec![(0, Judgement::Reasonable)],
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn account_without_identity_has_an_empty_profile() {
+		new_identity_ext().execute_with(|| {
+			assert_eq!(AccountProfile::of(&alice()), AccountProfile { display: None, judgements: vec![] });
+		});
+	}
+
+	#[test]
+	fn runtime_apis_return_owner_profiles() {
+		use pallet_kitties_runtime_api::runtime_decl_for_KittiesApi::KittiesApi;
+		use pallet_poe_runtime_api::runtime_decl_for_PoeApi::PoeApi;
+
+		new_identity_ext().execute_with(|| {
+			set_display(alice(), b"alice");
+			assert_ok!(KittiesModule::create(Origin::signed(alice())));
+			assert_ok!(PoeModule::create_claim(Origin::signed(alice()), claim(b"hello")));
+
+			let profile = AccountProfile { display: Some(b"alice".to_vec()), judgements: vec![] };
+			let dna = KittiesModule::kitties(0).unwrap().0;
+			assert_eq!(
+				<Runtime as KittiesApi<Block, _, _, _>>::kitty(0),
+				Some((alice(), dna, profile.clone()))
+			);
+			assert_eq!(
+				<Runtime as PoeApi<Block, _, _, _>>::claim(claim(b"hello").into_inner()),
+				Some((alice(), 1, profile))
+			);
+			assert_eq!(<Runtime as PoeApi<Block, _, _, _>>::claim(vec![0; 300]), None);
+			assert_eq!(<Runtime as KittiesApi<Block, _, _, _>>::kitty(1), None);
+		});
+	}
+}
+
+// 9. pallet_scheduler / pallet_preimage
+mod scheduler {
+	use super::*;
+	use frame_support::traits::{
+		schedule::{Anon, DispatchTime, MaybeHashed},
+		OnInitialize,
+	};
+	use sp_runtime::{traits::Hash, DispatchError};
+
+	fn revoke_claim(data: &[u8]) -> Call {
+		Call::PoeModule(pallet_poe::Call::revoke_claim { proof: claim(data) })
+	}
+
+	fn force_revoke_claim(data: &[u8]) -> Call {
+		Call::PoeModule(pallet_poe::Call::force_revoke_claim { proof: claim(data), reason: 0 })
+	}
+
+	// Run the scheduler of every block up to and including `n`.
+	fn run_to_block(n: BlockNumber) {
+		while System::block_number() < n {
+			System::set_block_number(System::block_number() + 1);
+			Scheduler::on_initialize(System::block_number());
+		}
+	}
+
+	#[test]
+	fn scheduled_revoke_claim_executes_at_the_target_block() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(PoeModule::create_claim(Origin::signed(alice()), claim(b"hello")));
+			let origin: OriginCaller = frame_system::RawOrigin::Signed(alice()).into();
+			assert_ok!(<Scheduler as Anon<_, _, _>>::schedule(
+				DispatchTime::At(5),
+				None,
+				0,
+				origin,
+				revoke_claim(b"hello"),
+			));
+
+			run_to_block(4);
+			assert_eq!(PoeModule::claims_of(alice()).into_inner(), vec![claim(b"hello")]);
+
+			run_to_block(5);
+			assert!(PoeModule::claims_of(alice()).is_empty());
+		});
+	}
+
+	#[test]
+	fn root_schedules_a_call_by_preimage_hash() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(PoeModule::create_claim(Origin::signed(bob()), claim(b"fraud")));
+			let call = force_revoke_claim(b"fraud");
+			let hash = BlakeTwo256::hash_of(&call);
+			assert_ok!(Preimage::note_preimage(Origin::signed(alice()), call.encode()));
+
+			assert_ok!(Scheduler::schedule(
+				Origin::root(),
+				3,
+				None,
+				0,
+				Box::new(MaybeHashed::Hash(hash))
+			));
+			run_to_block(3);
+			assert!(PoeModule::claims_of(bob()).is_empty());
+		});
+	}
+
+	#[test]
+	fn only_root_can_schedule() {
+		new_test_ext().execute_with(|| {
+			let call = Box::new(MaybeHashed::Value(force_revoke_claim(b"fraud")));
+			assert_eq!(
+				Scheduler::schedule(Origin::signed(alice()), 3, None, 0, call),
+				Err(DispatchError::BadOrigin)
+			);
+		});
+	}
+}
+
+// 10. pallet_contracts with the kitties chain extension
+mod contracts {
+	use super::*;
+	use crate::chain_extension::{
+		FUNC_KITTY_DNA, FUNC_KITTY_OWNER, FUNC_KITTY_TRANSFOR, FUNC_KITTY_TRANSFOR_FROM_CALLER,
+	};
+	use codec::Decode;
+	use pallet_contracts_primitives::Code;
+	use sp_runtime::DispatchError;
+
+	// Forwards its input to the chain extension and returns the output. The input starts with the
+	// func_id, followed by the encoded arguments.
+	const FORWARDER: &str = r#"
+	(module
+		(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+		(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+		(import "seal0" "seal_call_chain_extension"
+			(func $seal_call_chain_extension (param i32 i32 i32 i32 i32) (result i32)))
+		(import "env" "memory" (memory 1 1))
+
+		;; [0, 4) input length, [4, 260) input
+		(data (i32.const 0) "\00\01")
+		;; [300, 304) output length, [304, 560) output
+		(data (i32.const 300) "\00\01")
+
+		(func (export "deploy"))
+
+		(func (export "call")
+			(call $seal_input (i32.const 4) (i32.const 0))
+			(drop (call $seal_call_chain_extension
+				(i32.load (i32.const 4))
+				(i32.const 8)
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4))
+				(i32.const 304)
+				(i32.const 300)
+			))
+			(call $seal_return (i32.const 0) (i32.const 304) (i32.load (i32.const 300)))
+		)
+	)
+	"#;
+
+	fn gas_limit() -> Weight {
+		BlockWeights::get().max_block
+	}
+
+	// Alice deploys the forwarder with 10 DOLLARS so it can hold kitty deposits.
+	fn deploy_forwarder() -> AccountId {
+		assert_ok!(Balances::set_balance(Origin::root(), alice().into(), 10_000 * DOLLARS, 0));
+		let wasm = wat::parse_str(FORWARDER).unwrap();
+		Contracts::bare_instantiate(
+			alice(),
+			10 * DOLLARS,
+			gas_limit(),
+			None,
+			Code::Upload(wasm.into()),
+			vec![],
+			vec![],
+			false,
+		)
+		.result
+		.unwrap()
+		.account_id
+	}
+
+	fn call_extension(contract: &AccountId, input: Vec<u8>) -> Result<Vec<u8>, DispatchError> {
+		Contracts::bare_call(alice(), contract.clone(), 0, gas_limit(), None, input, false)
+			.result
+			.map(|ret| ret.data[..].to_vec())
+	}
+
+	#[test]
+	fn contract_reads_kitty_owner_and_dna() {
+		new_test_ext().execute_with(|| {
+			let contract = deploy_forwarder();
+			assert_ok!(KittiesModule::create(Origin::signed(bob())));
+			let dna = KittiesModule::kitties(0).unwrap().0;
+
+			let owner = call_extension(&contract, (FUNC_KITTY_OWNER, 0u32).encode()).unwrap();
+			assert_eq!(Option::<AccountId>::decode(&mut &owner[..]).unwrap(), Some(bob()));
+			let missing = call_extension(&contract, (FUNC_KITTY_OWNER, 1u32).encode()).unwrap();
+			assert_eq!(Option::<AccountId>::decode(&mut &missing[..]).unwrap(), None);
+
+			let read = call_extension(&contract, (FUNC_KITTY_DNA, 0u32).encode()).unwrap();
+			assert_eq!(Option::<[u8; 16]>::decode(&mut &read[..]).unwrap(), Some(dna));
+		});
+	}
+
+	#[test]
+	fn contract_transfors_its_own_kitty() {
+		new_test_ext().execute_with(|| {
+			let contract = deploy_forwarder();
+			assert_ok!(KittiesModule::create(Origin::signed(alice())));
+			assert_ok!(KittiesModule::transfor(Origin::signed(alice()), 0, contract.clone()));
+
+			assert_ok!(call_extension(&contract, (FUNC_KITTY_TRANSFOR, 0u32, bob()).encode()));
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(bob()));
+		});
+	}
+
+	#[test]
+	fn contract_cannot_transfor_the_callers_kitty() {
+		new_test_ext().execute_with(|| {
+			let contract = deploy_forwarder();
+			assert_ok!(KittiesModule::create(Origin::signed(alice())));
+
+			assert!(call_extension(&contract, (FUNC_KITTY_TRANSFOR, 0u32, bob()).encode()).is_err());
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(alice()));
+		});
+	}
+
+	#[test]
+	fn contract_transfors_the_callers_kitty_only_when_approved() {
+		new_test_ext().execute_with(|| {
+			let contract = deploy_forwarder();
+			assert_ok!(KittiesModule::create(Origin::signed(alice())));
+			assert_ok!(KittiesModule::create(Origin::signed(alice())));
+
+			// Without an allowance from the caller, and an allowance from someone else does not count.
+			let transfor_0 = (FUNC_KITTY_TRANSFOR_FROM_CALLER, 0u32, bob()).encode();
+			assert!(call_extension(&contract, transfor_0.clone()).is_err());
+			assert_ok!(KittiesModule::approve_operator(Origin::signed(bob()), contract.clone()));
+			assert!(call_extension(&contract, transfor_0.clone()).is_err());
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(alice()));
+
+			assert_ok!(KittiesModule::approve_operator(Origin::signed(alice()), contract.clone()));
+			assert_ok!(call_extension(&contract, transfor_0));
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(bob()));
+
+			assert_ok!(KittiesModule::cancel_operator(Origin::signed(alice()), contract.clone()));
+			assert!(call_extension(&contract, (FUNC_KITTY_TRANSFOR_FROM_CALLER, 1u32, bob()).encode()).is_err());
+			assert_eq!(KittiesModule::kitty_onwer(1), Some(alice()));
+		});
+	}
+
+	#[test]
+	fn paused_transfor_is_rejected_for_contracts() {
+		new_test_ext().execute_with(|| {
+			let contract = deploy_forwarder();
+			assert_ok!(KittiesModule::create(Origin::signed(alice())));
+			assert_ok!(KittiesModule::transfor(Origin::signed(alice()), 0, contract.clone()));
+			assert_ok!(Pause::pause(
+				Origin::root(),
+				b"KittiesModule".to_vec(),
+				Some(b"transfor".to_vec())
+			));
+
+			assert!(call_extension(&contract, (FUNC_KITTY_TRANSFOR, 0u32, bob()).encode()).is_err());
+			assert_eq!(KittiesModule::kitty_onwer(0), Some(contract));
+		});
+	}
+}
+
+// 11. pallet_vesting: locked genesis balances and kitty reserves
+mod vesting {
+	use super::*;
+	use frame_support::assert_noop;
+
+	// Charlie is endowed with 100 DOLLARS of which `liquid` is free and the rest vests by
+	// 1 DOLLAR per block from genesis.
+	fn new_vesting_ext(liquid: Balance) -> sp_io::TestExternalities {
+		let length = ((100 * DOLLARS - liquid) / DOLLARS) as BlockNumber;
+		ExtBuilder::default()
+			.balance(charlie(), 100 * DOLLARS)
+			.vesting(charlie(), 0, length, liquid)
+			.build()
+	}
+
+	#[test]
+	fn genesis_schedule_unlocks_linearly() {
+		new_vesting_ext(0).execute_with(|| {
+			assert_eq!(Vesting::vesting_balance(&charlie()), Some(99 * DOLLARS));
+			System::set_block_number(40);
+			assert_eq!(Vesting::vesting_balance(&charlie()), Some(60 * DOLLARS));
+			System::set_block_number(100);
+			assert_eq!(Vesting::vesting_balance(&charlie()), Some(0));
+		});
+	}
+
+	#[test]
+	fn unvested_funds_cannot_be_transferred_or_reserved() {
+		new_vesting_ext(0).execute_with(|| {
+			assert!(Balances::transfer(Origin::signed(charlie()), alice().into(), DOLLARS).is_err());
+			assert_noop!(
+				KittiesModule::create(Origin::signed(charlie())),
+				pallet_kitties::Error::<Runtime>::BalanceNotEnough
+			);
+		});
+	}
+
+	#[test]
+	fn kitties_reserve_vested_funds() {
+		new_vesting_ext(0).execute_with(|| {
+			System::set_block_number(10);
+			assert_ok!(Vesting::vest(Origin::signed(charlie())));
+
+			assert_ok!(KittiesModule::create(Origin::signed(charlie())));
+			assert_eq!(Balances::reserved_balance(&charlie()), 64);
+			assert_ok!(KittiesModule::transfor(Origin::signed(charlie()), 0, alice()));
+			assert_eq!(Balances::reserved_balance(&charlie()), 0);
+		});
+	}
+
+	#[test]
+	fn kitties_reserve_the_liquid_part_before_anything_vests() {
+		new_vesting_ext(DOLLARS).execute_with(|| {
+			assert_ok!(KittiesModule::create(Origin::signed(charlie())));
+			assert_ok!(KittiesModule::create(Origin::signed(alice())));
+			assert_ok!(KittiesModule::transfor(Origin::signed(alice()), 1, charlie()));
+			assert_eq!(Balances::reserved_balance(&charlie()), 128);
+		});
+	}
+}
+
+// 12. pallet_treasury: funded by fees, dust and slashed deposits
+mod treasury {
+	use super::*;
+	use frame_support::weights::{DispatchInfo, Pays, PostDispatchInfo};
+	use pallet_asset_tx_payment::ChargeAssetTxPayment;
+	use sp_runtime::traits::SignedExtension;
+
+	fn treasury_balance() -> Balance {
+		Balances::free_balance(Treasury::account_id())
+	}
+
+	fn new_treasury_ext() -> sp_io::TestExternalities {
+		let mut ext = new_test_ext();
+		ext.execute_with(|| {
+			// As in genesis, the treasury account holds the existential deposit.
+			let _ = Balances::deposit_creating(&Treasury::account_id(), 500);
+		});
+		ext
+	}
+
+	#[test]
+	fn treasury_receives_its_share_of_fees_and_tips() {
+		new_treasury_ext().execute_with(|| {
+			let call = create_kitty();
+			let info: DispatchInfo = call.get_dispatch_info();
+			let tip = 10 * CENTS;
+			let before = treasury_balance();
+			let issuance = Balances::total_issuance();
+
+			// Through the signed extension in `SignedExtra`, paying natively.
+			let pre = ChargeAssetTxPayment::<Runtime>::from(tip, None)
+				.pre_dispatch(&alice(), &call, &info, 100)
+				.unwrap();
+			let post_info = PostDispatchInfo { actual_weight: None, pays_fee: Pays::Yes };
+			assert_ok!(ChargeAssetTxPayment::<Runtime>::post_dispatch(
+				Some(pre),
+				&info,
+				&post_info,
+				100,
+				&Ok(())
+			));
+
+			let paid = TransactionPayment::compute_fee(100, &info, tip);
+			let share = FeeTreasuryShare::get() * paid;
+			assert_eq!(treasury_balance() - before, share);
+			assert_eq!(issuance - Balances::total_issuance(), paid - share);
+		});
+	}
+
+	#[test]
+	fn treasury_receives_dust_of_reaped_accounts() {
+		new_treasury_ext().execute_with(|| {
+			let before = treasury_balance();
+			// Leaving 100 behind, below the existential deposit of 500, reaps Bob's account.
+			assert_ok!(Balances::transfer(
+				Origin::signed(bob()),
+				alice().into(),
+				Balances::free_balance(bob()) - 100
+			));
+			assert_eq!(Balances::free_balance(bob()), 0);
+			assert_eq!(treasury_balance() - before, DustTreasuryShare::get() * 100);
+		});
+	}
+
+	#[test]
+	fn force_removed_kitty_deposit_goes_to_the_treasury() {
+		new_treasury_ext().execute_with(|| {
+			assert_ok!(KittiesModule::create(Origin::signed(bob())));
+			let before = treasury_balance();
+
+			assert!(KittiesModule::force_remove(Origin::signed(alice()), 0).is_err());
+			assert_ok!(KittiesModule::force_remove(Origin::root(), 0));
+
+			assert_eq!(KittiesModule::kitty_onwer(0), None);
+			assert!(KittiesModule::all_kts_owned(bob()).is_empty());
+			assert_eq!(Balances::reserved_balance(&bob()), 0);
+			assert_eq!(treasury_balance() - before, 64);
+		});
+	}
+
+	#[test]
+	fn force_revoked_claim_deposit_goes_to_the_treasury() {
+		new_treasury_ext().execute_with(|| {
+			assert_ok!(PoeModule::create_claim(Origin::signed(bob()), claim(b"fraud")));
+			assert_eq!(Balances::reserved_balance(&bob()), PoeClaimDeposit::get());
+			let before = treasury_balance();
+
+			assert_ok!(PoeModule::force_revoke_claim(Origin::root(), claim(b"fraud"), 0));
+
+			assert_eq!(Balances::reserved_balance(&bob()), 0);
+			assert_eq!(treasury_balance() - before, PoeClaimDeposit::get());
+		});
+	}
+
+	#[test]
+	fn revoked_claim_deposit_is_returned() {
+		new_treasury_ext().execute_with(|| {
+			let free = Balances::free_balance(bob());
+			assert_ok!(PoeModule::create_claim(Origin::signed(bob()), claim(b"hello")));
+			assert_ok!(PoeModule::revoke_claim(Origin::signed(bob()), claim(b"hello")));
+			assert_eq!(Balances::free_balance(bob()), free);
+		});
+	}
+
+	#[test]
+	fn council_majority_approves_a_spend() {
+		new_treasury_ext().execute_with(|| {
+			let _ = Balances::deposit_creating(&Treasury::account_id(), 100 * DOLLARS);
+			assert_ok!(Treasury::propose_spend(Origin::signed(alice()), 10 * DOLLARS, charlie().into()));
+			assert_eq!(Balances::reserved_balance(&alice()), ProposalBondMinimum::get());
+
+			assert!(Treasury::approve_proposal(Origin::signed(alice()), 0).is_err());
+			let proposal = Call::Treasury(pallet_treasury::Call::approve_proposal { proposal_id: 0 });
+			let length = proposal.encoded_size() as u32;
+			let weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Council::propose(Origin::signed(alice()), 2, Box::new(proposal), length));
+			assert_ok!(Council::vote(Origin::signed(alice()), hash, 0, true));
+			assert_ok!(Council::vote(Origin::signed(bob()), hash, 0, true));
+			assert_ok!(Council::close(Origin::signed(alice()), hash, 0, weight, length));
+
+			// Approved proposals are paid out at the end of the spend period.
+			Treasury::spend_funds();
+			assert_eq!(Balances::free_balance(charlie()), 10 * DOLLARS);
+			assert_eq!(Balances::reserved_balance(&alice()), 0);
+		});
+	}
+}
@@ -0,0 +1,34 @@
+#!/usr/bin/env python3
+# Local stand-in for the external timestamp log used by the PoE off-chain worker.
+#
+# It accepts the SCALE encoded `(block_number, digest, signer, signature)` batches POSTed by
+# `pallet_poe` and answers with a receipt; the worker records the blake2_256 hash of the receipt
+# on-chain. Start it next to a dev node and point the worker at it:
+#
+#   ./scripts/anchor_server.py 8000
+#   curl -H 'Content-Type: application/json' -d '{"id":1, "jsonrpc":"2.0", "method":"offchain_localStorageSet",
+#     "params":["PERSISTENT", "0x'$(printf 'poe::anchor-endpoint' | xxd -p)'", "0x'$(printf 'http://localhost:8000/anchor' | xxd -p)'"]}' \
+#     http://localhost:9933
+
+import hashlib
+import sys
+import time
+from http.server import BaseHTTPRequestHandler, HTTPServer
+
+
+class AnchorHandler(BaseHTTPRequestHandler):
+    def do_POST(self):
+        body = self.rfile.read(int(self.headers.get("Content-Length", 0)))
+        receipt = "{}:{}".format(int(time.time()), hashlib.sha256(body).hexdigest()).encode()
+        print("anchored {} bytes -> {}".format(len(body), receipt.decode()))
+
+        self.send_response(200)
+        self.send_header("Content-Type", "text/plain")
+        self.send_header("Content-Length", str(len(receipt)))
+        self.end_headers()
+        self.wfile.write(receipt)
+
+
+if __name__ == "__main__":
+    port = int(sys.argv[1]) if len(sys.argv) > 1 else 8000
+    HTTPServer(("127.0.0.1", port), AnchorHandler).serve_forever()
@@ -1,6 +1,6 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	AccountId, AuraConfig, BalancesConfig, CouncilMembershipConfig, GenesisConfig, GrandpaConfig,
+	Signature, SudoConfig, SystemConfig, UseStorageConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -59,6 +59,11 @@ pub fn development_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Alice//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Bob//stash"),
 				],
+				// Council members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+				],
 				true,
 			)
 		},
@@ -106,6 +111,12 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 					get_account_id_from_seed::<sr25519::Public>("Eve//stash"),
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
+				// Council members
+				vec![
+					get_account_id_from_seed::<sr25519::Public>("Alice"),
+					get_account_id_from_seed::<sr25519::Public>("Bob"),
+					get_account_id_from_seed::<sr25519::Public>("Charlie"),
+				],
 				true,
 			)
 		},
@@ -128,6 +139,7 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
 	endowed_accounts: Vec<AccountId>,
+	council_members: Vec<AccountId>,
 	_enable_println: bool,
 ) -> GenesisConfig {
 	GenesisConfig {
@@ -146,9 +158,31 @@ fn testnet_genesis(
 		grandpa: GrandpaConfig {
 			authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect(),
 		},
+		use_storage: use_storage_genesis(root_key.clone()),
 		sudo: SudoConfig {
 			// Assign network admin rights.
 			key: root_key,
 		},
+		// Seeded through `CouncilMembership`, which initializes the council's members.
+		council: Default::default(),
+		council_membership: CouncilMembershipConfig {
+			members: council_members,
+			phantom: Default::default(),
+		},
+	}
+}
+
+/// Demo class, students and dorms for `pallet_use_storage`, registered by `owner`.
+fn use_storage_genesis(owner: AccountId) -> UseStorageConfig {
+	let students = ["张三", "李四", "王五", "赵六"];
+	UseStorageConfig {
+		classes: vec![(1, 40), (2, 40)],
+		students: students
+			.iter()
+			.enumerate()
+			.map(|(i, name)| (i as u32 + 1, name.as_bytes().to_vec(), owner.clone()))
+			.collect(),
+		dorms: vec![(101, 4), (102, 4)],
+		beds: vec![(101, 0, 1), (101, 1, 2), (102, 0, 3)],
 	}
 }
@@ -0,0 +1,34 @@
+//! Benchmarking setup for pallet-pause
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Pause;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
+use frame_support::{sp_std::{prelude::*, vec}, traits::{EnsureOrigin, Get}};
+
+// 生成最大长度的名称（最坏情况：存储键最长）
+fn name<T: Config>(byte: u8) -> Vec<u8> {
+	vec![byte; T::MaxNameLength::get() as usize]
+}
+
+benchmarks! {
+	pause {
+		let origin = T::PauseOrigin::successful_origin();
+	}: _<T::Origin>(origin, name::<T>(b'p'), Some(name::<T>(b'c')))
+	verify {
+		assert!(Pallet::<T>::is_paused(&name::<T>(b'p'), &name::<T>(b'c')));
+	}
+
+	unpause {
+		let origin = T::PauseOrigin::successful_origin();
+		let pallet_name = NameOf::<T>::try_from(name::<T>(b'p')).expect("within MaxNameLength; qed");
+		let call_name = NameOf::<T>::try_from(name::<T>(b'c')).expect("within MaxNameLength; qed");
+		PausedCalls::<T>::insert(&pallet_name, &call_name, ());
+	}: _<T::Origin>(origin, name::<T>(b'p'), Some(name::<T>(b'c')))
+	verify {
+		assert!(!Pallet::<T>::is_paused(&name::<T>(b'p'), &name::<T>(b'c')));
+	}
+}
+
+impl_benchmark_test_suite!(Pause, crate::mock::new_test_ext(), crate::mock::Test);
@@ -4,16 +4,41 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{sp_std::vec, traits::Get, BoundedVec};
 use frame_system::RawOrigin;
 
+// 生成最长的存证
+fn proof<T: Config>() -> BoundedVec<u8, T::MaxBytesInHash> {
+	BoundedVec::try_from(vec![u8::MAX; T::MaxBytesInHash::get() as usize]).expect("MaxBytesInHash bytes; qed")
+}
+
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	create_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		let proof = proof::<T>();
+	}: _(RawOrigin::Signed(caller.clone()), proof.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&proof).map(|(owner, _)| owner), Some(caller));
+	}
+
+	revoke_claim {
+		let caller: T::AccountId = whitelisted_caller();
+		let proof = proof::<T>();
+		Proofs::<T>::insert(&proof, (caller.clone(), T::BlockNumber::default()));
+	}: _(RawOrigin::Signed(caller), proof.clone())
+	verify {
+		assert_eq!(Proofs::<T>::get(&proof), None);
+	}
+
+	trans_claim {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		let receiver: T::AccountId = account("receiver", 0, 0);
+		let proof = proof::<T>();
+		Proofs::<T>::insert(&proof, (caller.clone(), T::BlockNumber::default()));
+	}: _(RawOrigin::Signed(caller), proof.clone(), receiver.clone())
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_eq!(Proofs::<T>::get(&proof).map(|(owner, _)| owner), Some(receiver));
 	}
 }
 
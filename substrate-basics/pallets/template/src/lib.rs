@@ -2,6 +2,15 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 #[frame_support::pallet] // 定义功能模块
 pub mod pallet {
 	use frame_support::pallet_prelude::*;
@@ -18,6 +27,7 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage] // 定义存储单元
+	#[pallet::getter(fn proofs)]
 	pub(super) type Proofs<T: Config> = StorageMap<
 		_,
 		Blake2_128Concat,
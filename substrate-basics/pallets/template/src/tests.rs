@@ -1,20 +1,48 @@
 use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+
+fn proof() -> BoundedVec<u8, <Test as crate::Config>::MaxBytesInHash> {
+	BoundedVec::try_from(vec![0, 1]).unwrap()
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn create_claim_works() {
 	new_test_ext().execute_with(|| {
 		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(Origin::signed(1), 42));
+		assert_ok!(TemplateModule::create_claim(Origin::signed(1), proof()));
 		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
+		assert_eq!(TemplateModule::proofs(proof()), Some((1, 0)));
+	});
+}
+
+#[test]
+fn create_claim_fails_when_claim_exists() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TemplateModule::create_claim(Origin::signed(1), proof()));
+		assert_noop!(
+			TemplateModule::create_claim(Origin::signed(2), proof()),
+			Error::<Test>::ProofAlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn revoke_claim_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(TemplateModule::revoke_claim(Origin::signed(1), proof()), Error::<Test>::NoSuchProof);
+		assert_ok!(TemplateModule::create_claim(Origin::signed(1), proof()));
+		assert_noop!(TemplateModule::revoke_claim(Origin::signed(2), proof()), Error::<Test>::NotProofOwner);
+		assert_ok!(TemplateModule::revoke_claim(Origin::signed(1), proof()));
+		assert_eq!(TemplateModule::proofs(proof()), None);
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn trans_claim_works() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(TemplateModule::cause_error(Origin::signed(1)), Error::<Test>::NoneValue);
+		assert_ok!(TemplateModule::create_claim(Origin::signed(1), proof()));
+		assert_noop!(TemplateModule::trans_claim(Origin::signed(2), proof(), 3), Error::<Test>::NotProofOwner);
+		assert_ok!(TemplateModule::trans_claim(Origin::signed(1), proof(), 2));
+		assert_eq!(TemplateModule::proofs(proof()), Some((2, 0)));
 	});
 }
@@ -0,0 +1,146 @@
+//! RPC interface for pallet-use-storage.
+//!
+//! Wraps `UseStorageApi` so dashboards get students, dorms and classes as JSON instead of
+//! decoding raw storage.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+pub use pallet_use_storage_runtime_api::UseStorageApi as UseStorageRuntimeApi;
+
+/// A student as returned by `useStorage_student`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StudentInfo<AccountId> {
+	/// Account that registered the student.
+	pub owner: AccountId,
+	/// Student name.
+	pub name: String,
+	/// Optional major.
+	pub major: Option<String>,
+	/// Optional grade.
+	pub grade: Option<u8>,
+}
+
+/// A class as returned by `useStorage_class`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassInfo {
+	/// Maximum number of enrolled students.
+	pub capacity: u32,
+	/// Number of enrolled students.
+	pub enrolled: u32,
+	/// Whether the class still accepts enrollments.
+	pub open: bool,
+}
+
+/// A bed and the student occupying it, as returned by `useStorage_dormOccupancy`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Occupant {
+	/// Bed number inside the dorm.
+	pub bed: u32,
+	/// Student number.
+	pub student: u32,
+}
+
+/// Queries over `pallet_use_storage`.
+#[rpc]
+pub trait UseStorageApi<BlockHash, AccountId> {
+	/// Profile and owner of a student.
+	#[rpc(name = "useStorage_student")]
+	fn student(&self, student_number: u32, at: Option<BlockHash>) -> Result<Option<StudentInfo<AccountId>>>;
+
+	/// Occupied beds of a dorm, ordered by bed number.
+	#[rpc(name = "useStorage_dormOccupancy")]
+	fn dorm_occupancy(&self, dorm_number: u32, at: Option<BlockHash>) -> Result<Vec<Occupant>>;
+
+	/// Capacity, enrollment and status of a class.
+	#[rpc(name = "useStorage_class")]
+	fn class(&self, class_number: u32, at: Option<BlockHash>) -> Result<Option<ClassInfo>>;
+}
+
+/// Implements [`UseStorageApi`] on top of the runtime API.
+pub struct UseStorage<C, P> {
+	client: Arc<C>,
+	_marker: PhantomData<P>,
+}
+
+impl<C, P> UseStorage<C, P> {
+	/// Create new `UseStorage` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i64 {
+	fn from(e: Error) -> i64 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(Error::RuntimeError.into()),
+		message: "Unable to query use-storage.".into(),
+		data: Some(format!("{:?}", e).into()),
+	}
+}
+
+// 链上已保证是合法的UTF-8，这里仍然容错处理
+fn utf8(bytes: Vec<u8>) -> String {
+	String::from_utf8_lossy(&bytes).into_owned()
+}
+
+impl<C, Block, AccountId> UseStorageApi<<Block as BlockT>::Hash, AccountId> for UseStorage<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: UseStorageRuntimeApi<Block, AccountId>,
+	AccountId: Codec,
+{
+	fn student(
+		&self,
+		student_number: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<StudentInfo<AccountId>>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let student = api.student(&at, student_number).map_err(runtime_error)?;
+		Ok(student.map(|(owner, profile)| StudentInfo {
+			owner,
+			name: utf8(profile.name),
+			major: profile.major.map(utf8),
+			grade: profile.grade,
+		}))
+	}
+
+	fn dorm_occupancy(&self, dorm_number: u32, at: Option<<Block as BlockT>::Hash>) -> Result<Vec<Occupant>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let beds = api.dorm_occupancy(&at, dorm_number).map_err(runtime_error)?;
+		Ok(beds.into_iter().map(|(bed, student)| Occupant { bed, student }).collect())
+	}
+
+	fn class(&self, class_number: u32, at: Option<<Block as BlockT>::Hash>) -> Result<Option<ClassInfo>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+		let class = api.class(&at, class_number).map_err(runtime_error)?;
+		Ok(class.map(|class| ClassInfo { capacity: class.capacity, enrolled: class.enrolled, open: class.open }))
+	}
+}
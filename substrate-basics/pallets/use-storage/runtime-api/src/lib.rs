@@ -0,0 +1,21 @@
+//! Runtime API definition for pallet-use-storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::prelude::*;
+
+pub use pallet_use_storage::{ClassDetail, StudentProfile};
+
+sp_api::decl_runtime_apis! {
+	pub trait UseStorageApi<AccountId> where
+		AccountId: Codec,
+	{
+		/// 学生的登记账户和档案，字符串字段为UTF-8字节
+		fn student(student_number: u32) -> Option<(AccountId, StudentProfile<Vec<u8>>)>;
+		/// 寝室中已入住的(床号, 学号)，按床号排序
+		fn dorm_occupancy(dorm_number: u32) -> Vec<(u32, u32)>;
+		/// 班级信息
+		fn class(class_number: u32) -> Option<ClassDetail>;
+	}
+}
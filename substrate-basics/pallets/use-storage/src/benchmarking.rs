@@ -0,0 +1,176 @@
+//! Benchmarking setup for pallet-use-storage
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as UseStorage;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::{sp_std::{prelude::*, vec}, traits::{EnsureOrigin, Get}};
+use frame_system::RawOrigin;
+
+// 由owner登记学生，姓名取最大长度
+fn register<T: Config>(owner: &T::AccountId, student_number: u32) {
+	let name = NameOf::<T>::try_from(vec![b'a'; T::MaxNameLength::get() as usize]).expect("within MaxNameLength; qed");
+	StudentOwner::<T>::insert(student_number, owner);
+	StudentsInfo::<T>::insert(student_number, StudentProfile { name, major: None, grade: None });
+}
+
+// 由admin账户登记学生，调用者需要通过登记员身份管理学生（最坏情况：需要额外读取Registrars）
+fn registered_by_other<T: Config>(student_number: u32) -> T::AccountId {
+	let owner: T::AccountId = account("owner", 0, 0);
+	let caller: T::AccountId = whitelisted_caller();
+	register::<T>(&owner, student_number);
+	Registrars::<T>::insert(&caller, ());
+	caller
+}
+
+// 新建一个满床位的寝室
+fn dorm<T: Config>(dorm_number: u32) {
+	Dorms::<T>::insert(dorm_number, DormDetail { capacity: T::MaxBedsPerDorm::get(), occupied: 0 });
+}
+
+benchmarks! {
+	set_students_info {
+		let caller: T::AccountId = whitelisted_caller();
+		let name = vec![b'a'; T::MaxNameLength::get() as usize];
+	}: _(RawOrigin::Signed(caller.clone()), 0, name)
+	verify {
+		assert_eq!(StudentOwner::<T>::get(0), Some(caller));
+	}
+
+	set_students_profile {
+		let caller = registered_by_other::<T>(0);
+		let major = vec![b'm'; T::MaxNameLength::get() as usize];
+	}: _(RawOrigin::Signed(caller), 0, Some(major), Some(1))
+	verify {
+		assert_eq!(StudentsInfo::<T>::get(0).map(|profile| profile.grade), Some(Some(1)));
+	}
+
+	define_dorm {
+		// 最坏情况：寝室已存在且每个床位都有学生，需要逐个检查床号
+		let capacity = T::MaxBedsPerDorm::get();
+		let owner: T::AccountId = account("owner", 0, 0);
+		dorm::<T>(0);
+		for bed_number in 0 .. capacity {
+			register::<T>(&owner, bed_number);
+			UseStorage::<T>::do_assign(0, bed_number, bed_number);
+		}
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0, capacity)
+	verify {
+		assert_eq!(Dorms::<T>::get(0).map(|dorm| dorm.occupied), Some(capacity));
+	}
+
+	assign {
+		let caller = registered_by_other::<T>(0);
+		dorm::<T>(0);
+	}: _(RawOrigin::Signed(caller), 0, 0, 0)
+	verify {
+		assert_eq!(StudentBed::<T>::get(0), Some((0, 0)));
+	}
+
+	vacate {
+		let caller = registered_by_other::<T>(0);
+		dorm::<T>(0);
+		UseStorage::<T>::do_assign(0, 0, 0);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert_eq!(DormInfo::<T>::get(0, 0), None);
+	}
+
+	swap {
+		let caller = registered_by_other::<T>(0);
+		register::<T>(&account("owner", 0, 0), 1);
+		dorm::<T>(0);
+		dorm::<T>(1);
+		UseStorage::<T>::do_assign(0, 0, 0);
+		UseStorage::<T>::do_assign(1, 0, 1);
+	}: _(RawOrigin::Signed(caller), 0, 1)
+	verify {
+		assert_eq!(StudentBed::<T>::get(0), Some((1, 0)));
+		assert_eq!(DormInfo::<T>::get(0, 0), Some(1));
+	}
+
+	auto_assign {
+		let s in 1 .. T::MaxAutoAssign::get();
+		// d个已满的候选寝室排在前面，只需读取寝室信息就跳过
+		let d in 0 .. T::MaxAutoAssignDorms::get() - T::MaxAutoAssign::get();
+		let owner: T::AccountId = account("owner", 0, 0);
+		let beds = T::MaxBedsPerDorm::get();
+		for dorm_number in 0 .. d {
+			Dorms::<T>::insert(dorm_number, DormDetail { capacity: beds, occupied: beds });
+		}
+		// 之后的每个寝室只剩最后一个空床位，分配每个学生都需要扫描一个完整的寝室
+		let mut occupant = s;
+		for dorm_number in d .. d + s {
+			dorm::<T>(dorm_number);
+			for bed_number in 0 .. beds - 1 {
+				register::<T>(&owner, occupant);
+				UseStorage::<T>::do_assign(dorm_number, bed_number, occupant);
+				occupant += 1;
+			}
+		}
+		let students: Vec<u32> = (0 .. s).collect();
+		for student_number in students.iter() {
+			register::<T>(&owner, *student_number);
+		}
+		let dorms: Vec<u32> = (0 .. d + s).collect();
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, students, dorms)
+	verify {
+		for student_number in 0 .. s {
+			assert!(StudentBed::<T>::contains_key(student_number));
+		}
+	}
+
+	create_class {
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0, T::MaxClassCapacity::get())
+	verify {
+		assert!(Classes::<T>::contains_key(0));
+	}
+
+	close_class {
+		Classes::<T>::insert(0, ClassDetail { capacity: T::MaxClassCapacity::get(), enrolled: 0, open: true });
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, 0)
+	verify {
+		assert_eq!(Classes::<T>::get(0).map(|class| class.open), Some(false));
+	}
+
+	enroll {
+		let caller = registered_by_other::<T>(0);
+		Classes::<T>::insert(0, ClassDetail { capacity: T::MaxClassCapacity::get(), enrolled: 0, open: true });
+	}: _(RawOrigin::Signed(caller), 0, 0)
+	verify {
+		assert!(Enrollments::<T>::contains_key(0, 0));
+	}
+
+	withdraw {
+		let caller = registered_by_other::<T>(0);
+		Classes::<T>::insert(0, ClassDetail { capacity: T::MaxClassCapacity::get(), enrolled: 1, open: true });
+		Enrollments::<T>::insert(0, 0, ());
+	}: _(RawOrigin::Signed(caller), 0, 0)
+	verify {
+		assert!(!Enrollments::<T>::contains_key(0, 0));
+	}
+
+	add_registrar {
+		let registrar: T::AccountId = account("registrar", 0, 0);
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, registrar.clone())
+	verify {
+		assert!(Registrars::<T>::contains_key(&registrar));
+	}
+
+	remove_registrar {
+		let registrar: T::AccountId = account("registrar", 0, 0);
+		Registrars::<T>::insert(&registrar, ());
+		let origin = T::AdminOrigin::successful_origin();
+	}: _<T::Origin>(origin, registrar.clone())
+	verify {
+		assert!(!Registrars::<T>::contains_key(&registrar));
+	}
+}
+
+impl_benchmark_test_suite!(UseStorage, crate::mock::new_test_ext(), crate::mock::Test);
@@ -2,77 +2,534 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::{*, OptionQuery, StorageMap}, Blake2_128Concat};
 	use frame_system::{pallet_prelude::*, ensure_signed};
+	use frame_support::sp_std::prelude::*;
+	use crate::WeightInfo;
+
+	// 当前存储版本，v1将学生姓名从u128改为UTF-8字符串，v2增加寝室定义和学生床位索引，
+	// v3将单个班级改为班级注册表，v4为没有拥有者的旧学生补上拥有者
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
+	pub type NameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
+
+	// 学生档案，姓名必填，专业和年级可选
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct StudentProfile<Name> {
+		pub name: Name,
+		pub major: Option<Name>,
+		pub grade: Option<u8>,
+	}
+
+	// 班级信息，关闭后不能再报名，已报名的学生仍可退出
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct ClassDetail {
+		pub capacity: u32,
+		pub enrolled: u32,
+		pub open: bool,
+	}
+
+	// 寝室信息，床号从0开始，小于capacity
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+	pub struct DormDetail {
+		pub capacity: u32,
+		pub occupied: u32,
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		// 管理员权限，可以修改任何学生信息、管理登记员
+		type AdminOrigin: EnsureOrigin<Self::Origin>;
+
+		// 姓名、专业等字符串字段的最大字节数
+		#[pallet::constant]
+		type MaxNameLength: Get<u32>;
+
+		// 每个寝室最多的床位数
+		#[pallet::constant]
+		type MaxBedsPerDorm: Get<u32>;
+
+		// auto_assign一次最多分配的学生数
+		#[pallet::constant]
+		type MaxAutoAssign: Get<u32>;
+
+		// auto_assign一次最多扫描的候选寝室数，应不小于MaxAutoAssign
+		#[pallet::constant]
+		type MaxAutoAssignDorms: Get<u32>;
+
+		// 每个班级最多的学生数
+		#[pallet::constant]
+		type MaxClassCapacity: Get<u32>;
+
+		// v4迁移时作为没有拥有者的旧学生的拥有者，迁移会同时把它加为登记员
+		type LegacyStudentOwner: Get<Self::AccountId>;
+
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+				.saturating_add(crate::migrations::v2::migrate::<T>())
+				.saturating_add(crate::migrations::v3::migrate::<T>())
+				.saturating_add(crate::migrations::v4::migrate::<T>())
+		}
+	}
+
 
-	// Class用于存储班级编号，只有root权限才能操作
+	// Classes用于存储班级编号与班级信息的对应关系，只有管理员才能创建和关闭班级
 	#[pallet::storage]
-	#[pallet::getter(fn my_class)]
-	pub type Class<T:Config> = StorageValue<_, u32>;
+	#[pallet::getter(fn classes)]
+	pub type Classes<T:Config> = StorageMap<_, Blake2_128Concat, u32, ClassDetail, OptionQuery>;
 
-	// StudentsInfo用于存储学号与姓名的对应关系
+	// Enrollments用于存储班级编号、学号与报名关系
+	#[pallet::storage]
+	#[pallet::getter(fn enrollments)]
+	pub type Enrollments<T:Config> = StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u32, (), OptionQuery>;
+
+	// StudentsInfo用于存储学号与学生档案的对应关系
 	#[pallet::storage]
 	#[pallet::getter(fn students_info)]
-	pub type StudentsInfo<T:Config> = StorageMap<_, Blake2_128Concat, u32, u128, OptionQuery>;
+	pub type StudentsInfo<T:Config> = StorageMap<_, Blake2_128Concat, u32, StudentProfile<NameOf<T>>, OptionQuery>;
+
+	// StudentOwner用于存储学号与登记该学生的账户之间的对应关系，只有该账户、登记员或管理员才能修改学生信息
+	#[pallet::storage]
+	#[pallet::getter(fn student_owner)]
+	pub type StudentOwner<T:Config> = StorageMap<_, Blake2_128Concat, u32, T::AccountId, OptionQuery>;
+
+	// Registrars用于存储登记员账户，登记员可以管理所有学生
+	#[pallet::storage]
+	#[pallet::getter(fn registrars)]
+	pub type Registrars<T:Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
 
 	// DormInfo用于存储寝室号、床号与学号之间的对应关系
 	#[pallet::storage]
 	#[pallet::getter(fn dorm_info)]
 	pub type DormInfo<T:Config> = StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, u32,  u32, OptionQuery>;
 
+	// Dorms用于存储寝室号与床位数、已入住人数的对应关系
+	#[pallet::storage]
+	#[pallet::getter(fn dorms)]
+	pub type Dorms<T:Config> = StorageMap<_, Blake2_128Concat, u32, DormDetail, OptionQuery>;
+
+	// StudentBed是DormInfo的反向索引，存储学号与(寝室号, 床号)的对应关系
+	#[pallet::storage]
+	#[pallet::getter(fn student_bed)]
+	pub type StudentBed<T:Config> = StorageMap<_, Blake2_128Concat, u32, (u32, u32), OptionQuery>;
+
+	// 创世配置，方便演示时直接带上班级、学生和寝室数据
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		// (班级编号, 容量)
+		pub classes: Vec<(u32, u32)>,
+		// (学号, 姓名, 登记账户)
+		pub students: Vec<(u32, Vec<u8>, T::AccountId)>,
+		// (寝室号, 床位数)
+		pub dorms: Vec<(u32, u32)>,
+		// (寝室号, 床号, 学号)
+		pub beds: Vec<(u32, u32, u32)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { classes: Vec::new(), students: Vec::new(), dorms: Vec::new(), beds: Vec::new() }
+		}
+	}
+
+	// 与对应的交易使用相同的校验，数据不合法时创世失败
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (class, capacity) in self.classes.iter() {
+				assert!(*capacity <= T::MaxClassCapacity::get(), "class {} exceeds MaxClassCapacity", class);
+				assert!(!Classes::<T>::contains_key(class), "duplicate class {}", class);
+				Classes::<T>::insert(class, ClassDetail { capacity: *capacity, enrolled: 0, open: true });
+			}
+
+			for (student_number, name, owner) in self.students.iter() {
+				assert!(!StudentsInfo::<T>::contains_key(student_number), "duplicate student {}", student_number);
+				let name = Pallet::<T>::bounded_name(name.clone())
+					.unwrap_or_else(|_| panic!("invalid name for student {}", student_number));
+				StudentOwner::<T>::insert(student_number, owner);
+				StudentsInfo::<T>::insert(student_number, StudentProfile { name, major: None, grade: None });
+			}
+
+			for (dorm_number, capacity) in self.dorms.iter() {
+				assert!(*capacity <= T::MaxBedsPerDorm::get(), "dorm {} exceeds MaxBedsPerDorm", dorm_number);
+				assert!(!Dorms::<T>::contains_key(dorm_number), "duplicate dorm {}", dorm_number);
+				Dorms::<T>::insert(dorm_number, DormDetail { capacity: *capacity, occupied: 0 });
+			}
+
+			for (dorm_number, bed_number, student_number) in self.beds.iter() {
+				let dorm = Dorms::<T>::get(dorm_number).unwrap_or_else(|| panic!("unknown dorm {}", dorm_number));
+				assert!(*bed_number < dorm.capacity, "bed {} out of range in dorm {}", bed_number, dorm_number);
+				assert!(
+					!DormInfo::<T>::contains_key(dorm_number, bed_number),
+					"duplicate bed {} in dorm {}", bed_number, dorm_number
+				);
+				assert!(StudentsInfo::<T>::contains_key(student_number), "unknown student {}", student_number);
+				assert!(!StudentBed::<T>::contains_key(student_number), "student {} assigned twice", student_number);
+				Pallet::<T>::do_assign(*dorm_number, *bed_number, *student_number);
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		SetClass(u32),
-		SetStudentsInfo(u32,u128),
-		SetDormInfo(u32,u32,u32),
+		ClassCreated(u32,u32),
+		ClassClosed(u32),
+		Enrolled(u32,u32),
+		Withdrawn(u32,u32),
+		SetStudentsInfo(u32,NameOf<T>),
+		SetStudentsProfile(u32,Option<NameOf<T>>,Option<u8>),
+		DormDefined(u32,u32),
+		Assigned(u32,u32,u32),
+		Vacated(u32,u32,u32),
+		Swapped(u32,u32),
+		RegistrarAdded(T::AccountId),
+		RegistrarRemoved(T::AccountId),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		SetClassDuplicate,
+		ClassAlreadyExists,
+		NoSuchClass,
+		ClassNotOpen,
+		ClassFull,
+		ClassCapacityTooLarge,
+		AlreadyEnrolled,
+		NotEnrolled,
+		NotStudentOwner,
+		NoSuchStudent,
+		BedOccupied,
+		RegistrarAlreadyExists,
+		NoSuchRegistrar,
+		InvalidUtf8,
+		NameTooLong,
+		NoSuchDorm,
+		NoSuchBed,
+		TooManyBeds,
+		DormCapacityTooSmall,
+		StudentAlreadyAssigned,
+		StudentNotAssigned,
+		SameStudent,
+		DuplicateStudent,
+		TooManyStudents,
+		NoFreeBed,
+		TooManyDorms,
+		DuplicateDorm,
 	}
 
 	#[pallet::call]
 	impl<T:Config> Pallet<T> {
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn set_class(origin: OriginFor<T>, class: u32) -> DispatchResultWithPostInfo{
-			ensure_root(origin)?;
-			if Class::<T>::exists() {
-				return Err(Error::<T>::SetClassDuplicate.into());
-			}
-			Class::<T>::put(class);
-			Self::deposit_event(Event::SetClass(class));
+		#[pallet::weight(T::WeightInfo::create_class())]
+		pub fn create_class(origin: OriginFor<T>, class: u32, capacity: u32) -> DispatchResultWithPostInfo{
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(capacity <= T::MaxClassCapacity::get(), Error::<T>::ClassCapacityTooLarge);
+			ensure!(!Classes::<T>::contains_key(class), Error::<T>::ClassAlreadyExists);
+			Classes::<T>::insert(class, ClassDetail { capacity, enrolled: 0, open: true });
+			Self::deposit_event(Event::ClassCreated(class, capacity));
+			Ok(().into())
+		}
+
+		// 关闭班级后不能再报名，已报名的记录保留
+		#[pallet::weight(T::WeightInfo::close_class())]
+		pub fn close_class(origin: OriginFor<T>, class: u32) -> DispatchResultWithPostInfo{
+			T::AdminOrigin::ensure_origin(origin)?;
+			Classes::<T>::try_mutate(class, |detail| -> DispatchResult {
+				let detail = detail.as_mut().ok_or(Error::<T>::NoSuchClass)?;
+				ensure!(detail.open, Error::<T>::ClassNotOpen);
+				detail.open = false;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::ClassClosed(class));
 			Ok(().into())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn set_students_info(origin: OriginFor<T>, student_number: u32, student_name: u128) -> DispatchResultWithPostInfo{ 
-			ensure_signed(origin)?;
-			StudentsInfo::<T>::insert(student_number, student_name);
+		// 学生报名开放中且未满员的班级，权限与修改学生信息相同
+		#[pallet::weight(T::WeightInfo::enroll())]
+		pub fn enroll(origin: OriginFor<T>, class: u32, student_number: u32) -> DispatchResultWithPostInfo{
+			let who = Self::ensure_admin_or_signed(origin)?;
+			Self::ensure_can_manage_student(&who, student_number)?;
+			ensure!(!Enrollments::<T>::contains_key(class, student_number), Error::<T>::AlreadyEnrolled);
+			Classes::<T>::try_mutate(class, |detail| -> DispatchResult {
+				let detail = detail.as_mut().ok_or(Error::<T>::NoSuchClass)?;
+				ensure!(detail.open, Error::<T>::ClassNotOpen);
+				ensure!(detail.enrolled < detail.capacity, Error::<T>::ClassFull);
+				detail.enrolled += 1;
+				Ok(())
+			})?;
+			Enrollments::<T>::insert(class, student_number, ());
+			Self::deposit_event(Event::Enrolled(class, student_number));
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		pub fn withdraw(origin: OriginFor<T>, class: u32, student_number: u32) -> DispatchResultWithPostInfo{
+			let who = Self::ensure_admin_or_signed(origin)?;
+			Self::ensure_can_manage_student(&who, student_number)?;
+			ensure!(Enrollments::<T>::contains_key(class, student_number), Error::<T>::NotEnrolled);
+			Enrollments::<T>::remove(class, student_number);
+			Classes::<T>::mutate(class, |detail| {
+				if let Some(detail) = detail {
+					detail.enrolled = detail.enrolled.saturating_sub(1);
+				}
+			});
+			Self::deposit_event(Event::Withdrawn(class, student_number));
+			Ok(().into())
+		}
+
+		// 新学生由调用者登记，已存在的学生只能由登记该学生的账户、登记员或管理员修改，
+		// 没有拥有者的旧学生不能被其他账户认领
+		#[pallet::weight(T::WeightInfo::set_students_info())]
+		pub fn set_students_info(origin: OriginFor<T>, student_number: u32, student_name: Vec<u8>) -> DispatchResultWithPostInfo{ 
+			let student_name = Self::bounded_name(student_name)?;
+			match Self::ensure_admin_or_signed(origin)? {
+				Some(who) => match StudentOwner::<T>::get(student_number) {
+					Some(owner) => ensure!(Self::can_manage(&who, &owner), Error::<T>::NotStudentOwner),
+					None if StudentsInfo::<T>::contains_key(student_number) =>
+						ensure!(Registrars::<T>::contains_key(&who), Error::<T>::NotStudentOwner),
+					None => StudentOwner::<T>::insert(student_number, &who),
+				},
+				// 管理员只能修改已登记的学生，不会成为学生的拥有者
+				None => ensure!(StudentsInfo::<T>::contains_key(student_number), Error::<T>::NoSuchStudent),
+			}
+			// 修改姓名时保留已有的专业和年级
+			StudentsInfo::<T>::mutate(student_number, |profile| match profile {
+				Some(profile) => profile.name = student_name.clone(),
+				None => *profile = Some(StudentProfile { name: student_name.clone(), major: None, grade: None }),
+			});
 			Self::deposit_event(Event::SetStudentsInfo(student_number, student_name));
 			Ok(().into())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		pub fn set_dorm_info(origin: OriginFor<T>, dorm_number: u32, bed_number: u32, student_number: u32) -> DispatchResultWithPostInfo{
-			ensure_signed(origin)?;
-			DormInfo::<T>::insert(dorm_number,bed_number, student_number);
-			Self::deposit_event(Event::SetDormInfo(dorm_number, bed_number, student_number));
+		// 修改已登记学生的专业和年级，权限与set_students_info相同
+		#[pallet::weight(T::WeightInfo::set_students_profile())]
+		pub fn set_students_profile(origin: OriginFor<T>, student_number: u32, major: Option<Vec<u8>>, grade: Option<u8>) -> DispatchResultWithPostInfo{
+			let who = Self::ensure_admin_or_signed(origin)?;
+			Self::ensure_can_manage_student(&who, student_number)?;
+			let major = major.map(Self::bounded_name).transpose()?;
+			StudentsInfo::<T>::try_mutate(student_number, |profile| -> DispatchResult {
+				let profile = profile.as_mut().ok_or(Error::<T>::NoSuchStudent)?;
+				profile.major = major.clone();
+				profile.grade = grade;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::SetStudentsProfile(student_number, major, grade));
+			Ok(().into())
+		}
+
+		// 新建寝室或修改寝室床位数，缩减床位时不能有学生住在被移除的床位上
+		#[pallet::weight(T::WeightInfo::define_dorm())]
+		pub fn define_dorm(origin: OriginFor<T>, dorm_number: u32, capacity: u32) -> DispatchResultWithPostInfo{
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(capacity <= T::MaxBedsPerDorm::get(), Error::<T>::TooManyBeds);
+			ensure!(
+				DormInfo::<T>::iter_key_prefix(dorm_number).all(|bed_number| bed_number < capacity),
+				Error::<T>::DormCapacityTooSmall
+			);
+			Dorms::<T>::mutate(dorm_number, |dorm| dorm.get_or_insert_with(Default::default).capacity = capacity);
+			Self::deposit_event(Event::DormDefined(dorm_number, capacity));
+			Ok(().into())
+		}
+
+		// 只能为已登记且尚未分配床位的学生分配空床位，床位已被占用时失败
+		#[pallet::weight(T::WeightInfo::assign())]
+		pub fn assign(origin: OriginFor<T>, dorm_number: u32, bed_number: u32, student_number: u32) -> DispatchResultWithPostInfo{
+			let who = Self::ensure_admin_or_signed(origin)?;
+			Self::ensure_can_manage_student(&who, student_number)?;
+			ensure!(!StudentBed::<T>::contains_key(student_number), Error::<T>::StudentAlreadyAssigned);
+			let dorm = Dorms::<T>::get(dorm_number).ok_or(Error::<T>::NoSuchDorm)?;
+			ensure!(bed_number < dorm.capacity, Error::<T>::NoSuchBed);
+			ensure!(!DormInfo::<T>::contains_key(dorm_number, bed_number), Error::<T>::BedOccupied);
+			Self::do_assign(dorm_number, bed_number, student_number);
 			Ok(().into())
 		}
+
+		// 学生搬出寝室，同时清除两个方向的索引
+		#[pallet::weight(T::WeightInfo::vacate())]
+		pub fn vacate(origin: OriginFor<T>, student_number: u32) -> DispatchResultWithPostInfo{
+			let who = Self::ensure_admin_or_signed(origin)?;
+			Self::ensure_can_manage_student(&who, student_number)?;
+			let (dorm_number, bed_number) = StudentBed::<T>::take(student_number).ok_or(Error::<T>::StudentNotAssigned)?;
+			DormInfo::<T>::remove(dorm_number, bed_number);
+			Dorms::<T>::mutate(dorm_number, |dorm| {
+				if let Some(dorm) = dorm {
+					dorm.occupied = dorm.occupied.saturating_sub(1);
+				}
+			});
+			Self::deposit_event(Event::Vacated(dorm_number, bed_number, student_number));
+			Ok(().into())
+		}
+
+		// 交换两个已分配床位的学生，调用者需要能管理这两个学生
+		#[pallet::weight(T::WeightInfo::swap())]
+		pub fn swap(origin: OriginFor<T>, student_a: u32, student_b: u32) -> DispatchResultWithPostInfo{
+			let who = Self::ensure_admin_or_signed(origin)?;
+			ensure!(student_a != student_b, Error::<T>::SameStudent);
+			Self::ensure_can_manage_student(&who, student_a)?;
+			Self::ensure_can_manage_student(&who, student_b)?;
+			let bed_a = StudentBed::<T>::get(student_a).ok_or(Error::<T>::StudentNotAssigned)?;
+			let bed_b = StudentBed::<T>::get(student_b).ok_or(Error::<T>::StudentNotAssigned)?;
+			StudentBed::<T>::insert(student_a, bed_b);
+			StudentBed::<T>::insert(student_b, bed_a);
+			DormInfo::<T>::insert(bed_a.0, bed_a.1, student_b);
+			DormInfo::<T>::insert(bed_b.0, bed_b.1, student_a);
+			Self::deposit_event(Event::Swapped(student_a, student_b));
+			Ok(().into())
+		}
+
+		// 管理员按dorms给出的顺序遍历候选寝室的空床位，依次分配给students，空床位不够时整体失败。
+		// 只扫描调用者给出的寝室，而不是遍历所有寝室，保证权重有上限
+		#[pallet::weight(T::WeightInfo::auto_assign(students.len() as u32, dorms.len() as u32))]
+		pub fn auto_assign(origin: OriginFor<T>, students: Vec<u32>, dorms: Vec<u32>) -> DispatchResultWithPostInfo{
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(students.len() as u32 <= T::MaxAutoAssign::get(), Error::<T>::TooManyStudents);
+			ensure!(dorms.len() as u32 <= T::MaxAutoAssignDorms::get(), Error::<T>::TooManyDorms);
+			let mut sorted = students.clone();
+			sorted.sort_unstable();
+			sorted.dedup();
+			ensure!(sorted.len() == students.len(), Error::<T>::DuplicateStudent);
+			let mut sorted = dorms.clone();
+			sorted.sort_unstable();
+			sorted.dedup();
+			ensure!(sorted.len() == dorms.len(), Error::<T>::DuplicateDorm);
+			for student_number in students.iter() {
+				ensure!(StudentsInfo::<T>::contains_key(student_number), Error::<T>::NoSuchStudent);
+				ensure!(!StudentBed::<T>::contains_key(student_number), Error::<T>::StudentAlreadyAssigned);
+			}
+
+			let mut free_beds = Vec::with_capacity(students.len());
+			for dorm_number in dorms {
+				if free_beds.len() == students.len() {
+					break;
+				}
+				let dorm = Dorms::<T>::get(dorm_number).ok_or(Error::<T>::NoSuchDorm)?;
+				if dorm.occupied >= dorm.capacity {
+					continue;
+				}
+				free_beds.extend(
+					(0..dorm.capacity)
+						.filter(|bed_number| !DormInfo::<T>::contains_key(dorm_number, bed_number))
+						.map(|bed_number| (dorm_number, bed_number))
+						.take(students.len() - free_beds.len()),
+				);
+			}
+			ensure!(free_beds.len() == students.len(), Error::<T>::NoFreeBed);
+
+			for (student_number, (dorm_number, bed_number)) in students.into_iter().zip(free_beds) {
+				Self::do_assign(dorm_number, bed_number, student_number);
+			}
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::add_registrar())]
+		pub fn add_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResultWithPostInfo{
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(!Registrars::<T>::contains_key(&registrar), Error::<T>::RegistrarAlreadyExists);
+			Registrars::<T>::insert(&registrar, ());
+			Self::deposit_event(Event::RegistrarAdded(registrar));
+			Ok(().into())
+		}
+
+		#[pallet::weight(T::WeightInfo::remove_registrar())]
+		pub fn remove_registrar(origin: OriginFor<T>, registrar: T::AccountId) -> DispatchResultWithPostInfo{
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(Registrars::<T>::contains_key(&registrar), Error::<T>::NoSuchRegistrar);
+			Registrars::<T>::remove(&registrar);
+			Self::deposit_event(Event::RegistrarRemoved(registrar));
+			Ok(().into())
+		}
+	}
+
+	impl<T:Config> Pallet<T> {
+		// 管理员返回None，普通签名账户返回Some(账户)
+		fn ensure_admin_or_signed(origin: OriginFor<T>) -> Result<Option<T::AccountId>, DispatchError> {
+			match T::AdminOrigin::try_origin(origin) {
+				Ok(_) => Ok(None),
+				Err(origin) => Ok(Some(ensure_signed(origin)?)),
+			}
+		}
+
+		// 管理员可以管理任何已登记的学生，签名账户需要是学生的拥有者或登记员，
+		// 没有拥有者的旧学生只有管理员和登记员可以管理
+		fn ensure_can_manage_student(who: &Option<T::AccountId>, student_number: u32) -> DispatchResult {
+			ensure!(StudentsInfo::<T>::contains_key(student_number), Error::<T>::NoSuchStudent);
+			if let Some(who) = who {
+				let allowed = match StudentOwner::<T>::get(student_number) {
+					Some(owner) => Self::can_manage(who, &owner),
+					None => Registrars::<T>::contains_key(who),
+				};
+				ensure!(allowed, Error::<T>::NotStudentOwner);
+			}
+			Ok(())
+		}
+
+		// 调用前需确保寝室、床位存在且为空，学生尚未分配床位
+		pub(crate) fn do_assign(dorm_number: u32, bed_number: u32, student_number: u32) {
+			DormInfo::<T>::insert(dorm_number, bed_number, student_number);
+			StudentBed::<T>::insert(student_number, (dorm_number, bed_number));
+			Dorms::<T>::mutate(dorm_number, |dorm| {
+				if let Some(dorm) = dorm {
+					dorm.occupied = dorm.occupied.saturating_add(1);
+				}
+			});
+			Self::deposit_event(Event::Assigned(dorm_number, bed_number, student_number));
+		}
+
+		// 供UseStorageApi查询，字符串字段转为普通字节数组
+		pub fn student(student_number: u32) -> Option<(T::AccountId, StudentProfile<Vec<u8>>)> {
+			let owner = StudentOwner::<T>::get(student_number)?;
+			let profile = StudentsInfo::<T>::get(student_number)?;
+			Some((owner, StudentProfile {
+				name: profile.name.into_inner(),
+				major: profile.major.map(|major| major.into_inner()),
+				grade: profile.grade,
+			}))
+		}
+
+		// 遍历DormInfo中该寝室的所有床位，按床号排序
+		pub fn dorm_occupancy(dorm_number: u32) -> Vec<(u32, u32)> {
+			let mut beds: Vec<(u32, u32)> = DormInfo::<T>::iter_prefix(dorm_number).collect();
+			beds.sort_unstable();
+			beds
+		}
+
+		// 校验输入是合法的UTF-8字符串且不超过MaxNameLength
+		fn bounded_name(name: Vec<u8>) -> Result<NameOf<T>, DispatchError> {
+			core::str::from_utf8(&name).map_err(|_| Error::<T>::InvalidUtf8)?;
+			NameOf::<T>::try_from(name).map_err(|_| Error::<T>::NameTooLong.into())
+		}
+
+		// 登记该学生的账户或者登记员可以管理学生
+		fn can_manage(who: &T::AccountId, owner: &T::AccountId) -> bool {
+			who == owner || Registrars::<T>::contains_key(who)
+		}
 	}
 }
\ No newline at end of file
@@ -0,0 +1,158 @@
+//! Storage migrations for pallet-use-storage
+
+use crate::{
+	ClassDetail, Classes, Config, DormDetail, DormInfo, Dorms, NameOf, Pallet, Registrars, StudentBed, StudentOwner,
+	StudentProfile, StudentsInfo,
+};
+use frame_support::{
+	sp_std::prelude::*,
+	storage::migration::take_storage_value,
+	traits::{Get, GetStorageVersion, PalletInfoAccess, StorageVersion},
+	weights::Weight,
+};
+
+pub mod v1 {
+	use super::*;
+
+	// v0 -> v1：StudentsInfo的值从u128改为StudentProfile
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut translated: Weight = 0;
+		StudentsInfo::<T>::translate::<u128, _>(|_, old| {
+			translated += 1;
+			Some(StudentProfile { name: decode_name::<T>(old), major: None, grade: None })
+		});
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+	}
+
+	// 旧客户端把ASCII姓名按大端序打包进u128，去掉首尾的0字节后若是合法UTF-8则直接使用，
+	// 否则保留原数字的十进制形式，保证旧数据不会丢失
+	pub fn decode_name<T: Config>(old: u128) -> NameOf<T> {
+		let bytes = old.to_be_bytes();
+		let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+		let end = bytes.iter().rposition(|b| *b != 0).map_or(start, |i| i + 1);
+		let packed = &bytes[start..end];
+
+		let name = match core::str::from_utf8(packed) {
+			Ok(s) if !s.is_empty() && !s.chars().any(char::is_control) => packed.to_vec(),
+			_ => decimal(old),
+		};
+		// u128的十进制最多39位，MaxNameLength过小时按字符边界截断
+		let mut name = name;
+		name.truncate(T::MaxNameLength::get() as usize);
+		while core::str::from_utf8(&name).is_err() {
+			name.pop();
+		}
+		NameOf::<T>::try_from(name).expect("truncated to MaxNameLength; qed")
+	}
+
+	fn decimal(mut n: u128) -> Vec<u8> {
+		let mut digits = Vec::new();
+		loop {
+			digits.push(b'0' + (n % 10) as u8);
+			n /= 10;
+			if n == 0 {
+				break
+			}
+		}
+		digits.reverse();
+		digits
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	// v1 -> v2：根据已有的DormInfo建立Dorms和StudentBed，床位数取已用的最大床号+1。
+	// 旧数据中同一个学生可能占了多个床位，只保留遍历到的第一个
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 2 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let beds: Vec<(u32, u32, u32)> = DormInfo::<T>::iter().collect();
+		let mut reads: Weight = 1 + beds.len() as Weight;
+		let mut writes: Weight = 1;
+		for (dorm_number, bed_number, student_number) in beds {
+			reads += 1;
+			if StudentBed::<T>::contains_key(student_number) {
+				DormInfo::<T>::remove(dorm_number, bed_number);
+				writes += 1;
+				continue
+			}
+			StudentBed::<T>::insert(student_number, (dorm_number, bed_number));
+			Dorms::<T>::mutate(dorm_number, |dorm| {
+				let dorm = dorm.get_or_insert_with(DormDetail::default);
+				dorm.capacity = dorm.capacity.max(bed_number.saturating_add(1));
+				dorm.occupied = dorm.occupied.saturating_add(1);
+			});
+			reads += 1;
+			writes += 2;
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
+pub mod v3 {
+	use super::*;
+
+	// v2 -> v3：把旧的单个Class值迁移为一个开放中的班级，容量取MaxClassCapacity
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 3 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let mut writes: Weight = 1;
+		if let Some(class) = take_storage_value::<u32>(Pallet::<T>::name().as_bytes(), b"Class", &[]) {
+			Classes::<T>::insert(class, ClassDetail { capacity: T::MaxClassCapacity::get(), enrolled: 0, open: true });
+			writes += 2;
+		}
+
+		StorageVersion::new(3).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(2, writes)
+	}
+}
+
+pub mod v4 {
+	use super::*;
+
+	// v3 -> v4：早期版本登记的学生没有StudentOwner，统一交给LegacyStudentOwner，
+	// 并把它加为登记员，避免任意账户通过set_students_info认领这些学生
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_version >= 4 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let owner = T::LegacyStudentOwner::get();
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 1;
+		for student_number in StudentsInfo::<T>::iter_keys() {
+			reads += 2;
+			if !StudentOwner::<T>::contains_key(student_number) {
+				StudentOwner::<T>::insert(student_number, &owner);
+				writes += 1;
+			}
+		}
+		if writes > 1 {
+			reads += 1;
+			if !Registrars::<T>::contains_key(&owner) {
+				Registrars::<T>::insert(&owner, ());
+				writes += 1;
+			}
+		}
+
+		StorageVersion::new(4).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
@@ -0,0 +1,479 @@
+use crate::{
+	mock::*, ClassDetail, DormDetail, DormInfo, Dorms, Enrollments, Error, NameOf, StudentOwner,
+	StudentProfile, StudentsInfo,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::DispatchError;
+
+fn name(s: &str) -> NameOf<Test> {
+	NameOf::<Test>::try_from(s.as_bytes().to_vec()).unwrap()
+}
+
+// 账户1登记学生1、2，账户2登记学生3
+fn register_students() {
+	assert_ok!(UseStorage::set_students_info(Origin::signed(1), 1, b"alice".to_vec()));
+	assert_ok!(UseStorage::set_students_info(Origin::signed(1), 2, b"bob".to_vec()));
+	assert_ok!(UseStorage::set_students_info(Origin::signed(2), 3, b"carol".to_vec()));
+}
+
+// 1. 学生信息
+#[test]
+fn set_students_info_records_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(UseStorage::set_students_info(Origin::signed(1), 1, "张三".as_bytes().to_vec()));
+		assert_eq!(UseStorage::student_owner(1), Some(1));
+		assert_eq!(
+			UseStorage::students_info(1),
+			Some(StudentProfile { name: name("张三"), major: None, grade: None })
+		);
+		System::assert_last_event(crate::Event::<Test>::SetStudentsInfo(1, name("张三")).into());
+	});
+}
+
+#[test]
+fn set_students_info_rejects_bad_names() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			UseStorage::set_students_info(Origin::signed(1), 1, vec![0xff, 0xfe]),
+			Error::<Test>::InvalidUtf8
+		);
+		assert_noop!(
+			UseStorage::set_students_info(Origin::signed(1), 1, vec![b'a'; 17]),
+			Error::<Test>::NameTooLong
+		);
+	});
+}
+
+#[test]
+fn only_owner_registrar_or_admin_can_update_student() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_noop!(
+			UseStorage::set_students_info(Origin::signed(2), 1, b"mallory".to_vec()),
+			Error::<Test>::NotStudentOwner
+		);
+
+		assert_ok!(UseStorage::add_registrar(Origin::root(), 2));
+		assert_ok!(UseStorage::set_students_info(Origin::signed(2), 1, b"alice2".to_vec()));
+		assert_ok!(UseStorage::set_students_info(Origin::root(), 1, b"alice3".to_vec()));
+		assert_eq!(UseStorage::students_info(1).unwrap().name, name("alice3"));
+		// 修改者不会成为新的拥有者
+		assert_eq!(UseStorage::student_owner(1), Some(1));
+
+		assert_ok!(UseStorage::remove_registrar(Origin::root(), 2));
+		assert_noop!(
+			UseStorage::set_students_info(Origin::signed(2), 1, b"mallory".to_vec()),
+			Error::<Test>::NotStudentOwner
+		);
+	});
+}
+
+#[test]
+fn admin_cannot_create_students() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			UseStorage::set_students_info(Origin::root(), 1, b"alice".to_vec()),
+			Error::<Test>::NoSuchStudent
+		);
+	});
+}
+
+#[test]
+fn registrars_are_managed_by_admin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(UseStorage::add_registrar(Origin::signed(1), 2), DispatchError::BadOrigin);
+		assert_ok!(UseStorage::add_registrar(Origin::root(), 2));
+		assert_noop!(UseStorage::add_registrar(Origin::root(), 2), Error::<Test>::RegistrarAlreadyExists);
+		assert_ok!(UseStorage::remove_registrar(Origin::root(), 2));
+		assert_noop!(UseStorage::remove_registrar(Origin::root(), 2), Error::<Test>::NoSuchRegistrar);
+	});
+}
+
+#[test]
+fn set_students_profile_keeps_name() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::set_students_profile(Origin::signed(1), 1, Some(b"math".to_vec()), Some(2)));
+		assert_eq!(
+			UseStorage::students_info(1),
+			Some(StudentProfile { name: name("alice"), major: Some(name("math")), grade: Some(2) })
+		);
+		assert_ok!(UseStorage::set_students_info(Origin::signed(1), 1, b"alice2".to_vec()));
+		assert_eq!(UseStorage::students_info(1).unwrap().major, Some(name("math")));
+
+		assert_noop!(
+			UseStorage::set_students_profile(Origin::signed(2), 1, None, None),
+			Error::<Test>::NotStudentOwner
+		);
+		assert_noop!(
+			UseStorage::set_students_profile(Origin::signed(1), 9, None, None),
+			Error::<Test>::NoSuchStudent
+		);
+	});
+}
+
+// 2. 寝室分配
+#[test]
+fn define_dorm_is_admin_only_and_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(UseStorage::define_dorm(Origin::signed(1), 1, 2), DispatchError::BadOrigin);
+		assert_noop!(UseStorage::define_dorm(Origin::root(), 1, 3), Error::<Test>::TooManyBeds);
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_eq!(UseStorage::dorms(1), Some(DormDetail { capacity: 2, occupied: 0 }));
+	});
+}
+
+#[test]
+fn define_dorm_cannot_drop_occupied_beds() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::assign(Origin::signed(1), 1, 1, 1));
+		assert_noop!(UseStorage::define_dorm(Origin::root(), 1, 1), Error::<Test>::DormCapacityTooSmall);
+		assert_ok!(UseStorage::vacate(Origin::signed(1), 1));
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 1));
+	});
+}
+
+#[test]
+fn assign_keeps_both_indexes() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::assign(Origin::signed(1), 1, 0, 1));
+		assert_eq!(UseStorage::dorm_info(1, 0), Some(1));
+		assert_eq!(UseStorage::student_bed(1), Some((1, 0)));
+		assert_eq!(UseStorage::dorms(1).unwrap().occupied, 1);
+		System::assert_last_event(crate::Event::<Test>::Assigned(1, 0, 1).into());
+	});
+}
+
+#[test]
+fn assign_checks_dorm_bed_and_student() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_noop!(UseStorage::assign(Origin::signed(1), 1, 0, 1), Error::<Test>::NoSuchDorm);
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_noop!(UseStorage::assign(Origin::signed(1), 1, 2, 1), Error::<Test>::NoSuchBed);
+		assert_noop!(UseStorage::assign(Origin::signed(1), 1, 0, 9), Error::<Test>::NoSuchStudent);
+		assert_noop!(UseStorage::assign(Origin::signed(2), 1, 0, 1), Error::<Test>::NotStudentOwner);
+
+		assert_ok!(UseStorage::assign(Origin::signed(1), 1, 0, 1));
+		assert_noop!(UseStorage::assign(Origin::signed(1), 1, 1, 1), Error::<Test>::StudentAlreadyAssigned);
+		assert_noop!(UseStorage::assign(Origin::signed(2), 1, 0, 3), Error::<Test>::BedOccupied);
+		assert_noop!(UseStorage::assign(Origin::root(), 1, 0, 3), Error::<Test>::BedOccupied);
+	});
+}
+
+#[test]
+fn vacate_clears_both_indexes() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::assign(Origin::signed(1), 1, 0, 1));
+		assert_noop!(UseStorage::vacate(Origin::signed(2), 1), Error::<Test>::NotStudentOwner);
+		assert_ok!(UseStorage::vacate(Origin::signed(1), 1));
+		assert_eq!(UseStorage::dorm_info(1, 0), None);
+		assert_eq!(UseStorage::student_bed(1), None);
+		assert_eq!(UseStorage::dorms(1).unwrap().occupied, 0);
+		System::assert_last_event(crate::Event::<Test>::Vacated(1, 0, 1).into());
+		assert_noop!(UseStorage::vacate(Origin::signed(1), 1), Error::<Test>::StudentNotAssigned);
+	});
+}
+
+#[test]
+fn swap_exchanges_beds() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 2, 2));
+		assert_ok!(UseStorage::assign(Origin::signed(1), 1, 0, 1));
+		assert_ok!(UseStorage::assign(Origin::signed(1), 2, 1, 2));
+		assert_ok!(UseStorage::assign(Origin::signed(2), 1, 1, 3));
+
+		assert_noop!(UseStorage::swap(Origin::signed(1), 1, 1), Error::<Test>::SameStudent);
+		// 账户1不能管理学生3
+		assert_noop!(UseStorage::swap(Origin::signed(1), 1, 3), Error::<Test>::NotStudentOwner);
+
+		assert_ok!(UseStorage::swap(Origin::signed(1), 1, 2));
+		assert_eq!(UseStorage::student_bed(1), Some((2, 1)));
+		assert_eq!(UseStorage::student_bed(2), Some((1, 0)));
+		assert_eq!(UseStorage::dorm_info(1, 0), Some(2));
+		assert_eq!(UseStorage::dorm_info(2, 1), Some(1));
+		assert_eq!(UseStorage::dorms(1).unwrap().occupied, 2);
+		assert_eq!(UseStorage::dorms(2).unwrap().occupied, 1);
+
+		assert_ok!(UseStorage::swap(Origin::root(), 1, 3));
+		assert_eq!(UseStorage::student_bed(3), Some((2, 1)));
+	});
+}
+
+#[test]
+fn auto_assign_fills_free_beds() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 2, 1));
+		assert_ok!(UseStorage::assign(Origin::signed(1), 1, 0, 1));
+
+		assert_noop!(UseStorage::auto_assign(Origin::signed(1), vec![2, 3], vec![1, 2]), DispatchError::BadOrigin);
+		assert_ok!(UseStorage::auto_assign(Origin::root(), vec![2, 3], vec![1, 2]));
+
+		for student_number in 1..=3 {
+			let (dorm_number, bed_number) = UseStorage::student_bed(student_number).unwrap();
+			assert_eq!(UseStorage::dorm_info(dorm_number, bed_number), Some(student_number));
+		}
+		assert_eq!(DormInfo::<Test>::iter().count(), 3);
+		assert_eq!(UseStorage::dorms(1).unwrap().occupied, 2);
+		assert_eq!(UseStorage::dorms(2).unwrap().occupied, 1);
+	});
+}
+
+#[test]
+fn auto_assign_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+
+		assert_noop!(UseStorage::auto_assign(Origin::root(), vec![1, 2, 3], vec![1]), Error::<Test>::NoFreeBed);
+		assert_noop!(UseStorage::auto_assign(Origin::root(), vec![1, 1], vec![1]), Error::<Test>::DuplicateStudent);
+		assert_noop!(UseStorage::auto_assign(Origin::root(), vec![1, 9], vec![1]), Error::<Test>::NoSuchStudent);
+		assert_noop!(
+			UseStorage::auto_assign(Origin::root(), vec![1, 2, 3, 4, 5], vec![1]),
+			Error::<Test>::TooManyStudents
+		);
+
+		assert_ok!(UseStorage::assign(Origin::signed(1), 1, 0, 1));
+		assert_noop!(UseStorage::auto_assign(Origin::root(), vec![1, 2], vec![1]), Error::<Test>::StudentAlreadyAssigned);
+	});
+}
+
+#[test]
+fn auto_assign_only_scans_candidate_dorms() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 2, 2));
+
+		// 寝室1有空床位，但不在候选列表中
+		assert_noop!(UseStorage::auto_assign(Origin::root(), vec![1, 2, 3], vec![2]), Error::<Test>::NoFreeBed);
+		assert_noop!(UseStorage::auto_assign(Origin::root(), vec![1], vec![7]), Error::<Test>::NoSuchDorm);
+		assert_noop!(UseStorage::auto_assign(Origin::root(), vec![1], vec![2, 2]), Error::<Test>::DuplicateDorm);
+		assert_noop!(
+			UseStorage::auto_assign(Origin::root(), vec![1], (0..9).collect()),
+			Error::<Test>::TooManyDorms
+		);
+
+		// 按候选寝室的顺序分配
+		assert_ok!(UseStorage::auto_assign(Origin::root(), vec![1, 2, 3], vec![2, 1]));
+		assert_eq!(UseStorage::student_bed(1), Some((2, 0)));
+		assert_eq!(UseStorage::student_bed(2), Some((2, 1)));
+		assert_eq!(UseStorage::student_bed(3), Some((1, 0)));
+	});
+}
+
+// 3. 班级
+#[test]
+fn create_class_is_admin_only_and_unique() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(UseStorage::create_class(Origin::signed(1), 1, 2), DispatchError::BadOrigin);
+		assert_noop!(UseStorage::create_class(Origin::root(), 1, 3), Error::<Test>::ClassCapacityTooLarge);
+		assert_ok!(UseStorage::create_class(Origin::root(), 1, 2));
+		System::assert_last_event(crate::Event::<Test>::ClassCreated(1, 2).into());
+		assert_noop!(UseStorage::create_class(Origin::root(), 1, 2), Error::<Test>::ClassAlreadyExists);
+		assert_ok!(UseStorage::create_class(Origin::root(), 2, 1));
+		assert_eq!(UseStorage::classes(1), Some(ClassDetail { capacity: 2, enrolled: 0, open: true }));
+	});
+}
+
+#[test]
+fn enroll_respects_capacity() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_noop!(UseStorage::enroll(Origin::signed(1), 1, 1), Error::<Test>::NoSuchClass);
+		assert_ok!(UseStorage::create_class(Origin::root(), 1, 2));
+
+		assert_ok!(UseStorage::enroll(Origin::signed(1), 1, 1));
+		System::assert_last_event(crate::Event::<Test>::Enrolled(1, 1).into());
+		assert_noop!(UseStorage::enroll(Origin::signed(1), 1, 1), Error::<Test>::AlreadyEnrolled);
+		assert_noop!(UseStorage::enroll(Origin::signed(1), 1, 3), Error::<Test>::NotStudentOwner);
+		assert_ok!(UseStorage::enroll(Origin::signed(2), 1, 3));
+		assert_noop!(UseStorage::enroll(Origin::signed(1), 1, 2), Error::<Test>::ClassFull);
+		assert_eq!(UseStorage::classes(1).unwrap().enrolled, 2);
+	});
+}
+
+#[test]
+fn withdraw_frees_a_seat() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_ok!(UseStorage::create_class(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::enroll(Origin::signed(1), 1, 1));
+		assert_ok!(UseStorage::enroll(Origin::signed(1), 1, 2));
+
+		assert_noop!(UseStorage::withdraw(Origin::signed(1), 1, 3), Error::<Test>::NotStudentOwner);
+		assert_noop!(UseStorage::withdraw(Origin::signed(2), 1, 3), Error::<Test>::NotEnrolled);
+		assert_ok!(UseStorage::withdraw(Origin::signed(1), 1, 1));
+		System::assert_last_event(crate::Event::<Test>::Withdrawn(1, 1).into());
+		assert_eq!(UseStorage::enrollments(1, 1), None);
+		assert_ok!(UseStorage::enroll(Origin::signed(2), 1, 3));
+	});
+}
+
+#[test]
+fn closed_class_rejects_enrollment() {
+	new_test_ext().execute_with(|| {
+		register_students();
+		assert_noop!(UseStorage::close_class(Origin::root(), 1), Error::<Test>::NoSuchClass);
+		assert_ok!(UseStorage::create_class(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::enroll(Origin::signed(1), 1, 1));
+		assert_noop!(UseStorage::close_class(Origin::signed(1), 1), DispatchError::BadOrigin);
+		assert_ok!(UseStorage::close_class(Origin::root(), 1));
+		assert_noop!(UseStorage::close_class(Origin::root(), 1), Error::<Test>::ClassNotOpen);
+
+		assert_noop!(UseStorage::enroll(Origin::signed(1), 1, 2), Error::<Test>::ClassNotOpen);
+		assert_ok!(UseStorage::withdraw(Origin::signed(1), 1, 1));
+	});
+}
+
+// 4. 存储迁移
+#[test]
+fn migrates_legacy_storage() {
+	new_test_ext().execute_with(|| {
+		use frame_support::storage::{migration::put_storage_value, unhashed};
+
+		StorageVersion::new(0).put::<UseStorage>();
+		// v0：姓名按大端序打包的u128，以及非ASCII的数字
+		let packed = u128::from_be_bytes(*b"\0\0\0\0\0\0\0\0\0\0\0alice");
+		unhashed::put(&StudentsInfo::<Test>::hashed_key_for(1), &packed);
+		unhashed::put(&StudentsInfo::<Test>::hashed_key_for(2), &1u128);
+		StudentOwner::<Test>::insert(1, 1);
+		StudentOwner::<Test>::insert(2, 1);
+		// v1：寝室分配没有反向索引，学生2重复占了两个床位
+		DormInfo::<Test>::insert(1, 0, 1);
+		DormInfo::<Test>::insert(1, 3, 2);
+		DormInfo::<Test>::insert(2, 0, 2);
+		// v2：单个班级
+		put_storage_value(b"UseStorage", b"Class", &[], 7u32);
+
+		<UseStorage as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(UseStorage::students_info(1).unwrap().name, name("alice"));
+		assert_eq!(UseStorage::students_info(2).unwrap().name, name("1"));
+
+		assert_eq!(UseStorage::student_bed(1), Some((1, 0)));
+		let (dorm_number, bed_number) = UseStorage::student_bed(2).unwrap();
+		assert_eq!(DormInfo::<Test>::iter().filter(|(_, _, student)| *student == 2).count(), 1);
+		assert_eq!(UseStorage::dorm_info(dorm_number, bed_number), Some(2));
+		let occupied: u32 = Dorms::<Test>::iter_values().map(|dorm| dorm.occupied).sum();
+		assert_eq!(occupied, 2);
+		assert!(Dorms::<Test>::get(1).unwrap().capacity >= 1);
+
+		assert_eq!(UseStorage::classes(7), Some(ClassDetail { capacity: 2, enrolled: 0, open: true }));
+		assert_eq!(StorageVersion::get::<UseStorage>(), StorageVersion::new(4));
+		assert!(!Enrollments::<Test>::contains_key(7, 1));
+		// 两个学生都有拥有者，不会添加登记员
+		assert_eq!(UseStorage::registrars(9), None);
+
+		// 再次升级不会修改数据
+		<UseStorage as OnRuntimeUpgrade>::on_runtime_upgrade();
+		assert_eq!(UseStorage::students_info(1).unwrap().name, name("alice"));
+	});
+}
+
+#[test]
+fn migration_gives_legacy_students_an_owner() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(3).put::<UseStorage>();
+		StudentsInfo::<Test>::insert(1, StudentProfile { name: name("alice"), major: None, grade: None });
+		StudentsInfo::<Test>::insert(2, StudentProfile { name: name("bob"), major: None, grade: None });
+		StudentOwner::<Test>::insert(2, 1);
+
+		<UseStorage as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+		assert_eq!(UseStorage::student_owner(1), Some(9));
+		assert_eq!(UseStorage::student_owner(2), Some(1));
+		assert_eq!(UseStorage::registrars(9), Some(()));
+		assert_eq!(StorageVersion::get::<UseStorage>(), StorageVersion::new(4));
+	});
+}
+
+#[test]
+fn legacy_students_cannot_be_claimed() {
+	new_test_ext().execute_with(|| {
+		// 迁移前没有拥有者的学生
+		StudentsInfo::<Test>::insert(1, StudentProfile { name: name("alice"), major: None, grade: None });
+		assert_ok!(UseStorage::define_dorm(Origin::root(), 1, 2));
+		assert_ok!(UseStorage::create_class(Origin::root(), 1, 2));
+
+		assert_noop!(
+			UseStorage::set_students_info(Origin::signed(2), 1, b"mallory".to_vec()),
+			Error::<Test>::NotStudentOwner
+		);
+		assert_noop!(UseStorage::assign(Origin::signed(2), 1, 0, 1), Error::<Test>::NotStudentOwner);
+
+		// 管理员和登记员仍可以管理，且不会成为拥有者
+		assert_ok!(UseStorage::assign(Origin::root(), 1, 0, 1));
+		assert_ok!(UseStorage::enroll(Origin::root(), 1, 1));
+		assert_ok!(UseStorage::add_registrar(Origin::root(), 3));
+		assert_ok!(UseStorage::set_students_info(Origin::signed(3), 1, b"alice2".to_vec()));
+		assert_ok!(UseStorage::vacate(Origin::signed(3), 1));
+		assert_eq!(UseStorage::student_owner(1), None);
+	});
+}
+
+// 5. 创世配置
+fn genesis_ext(beds: Vec<(u32, u32, u32)>) -> sp_io::TestExternalities {
+	use frame_support::traits::GenesisBuild;
+
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> {
+		classes: vec![(1, 2)],
+		students: vec![(1, b"alice".to_vec(), 1), (2, b"bob".to_vec(), 2)],
+		dorms: vec![(1, 2)],
+		beds,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	t.into()
+}
+
+#[test]
+fn genesis_populates_storage() {
+	genesis_ext(vec![(1, 0, 1), (1, 1, 2)]).execute_with(|| {
+		assert_eq!(UseStorage::classes(1), Some(ClassDetail { capacity: 2, enrolled: 0, open: true }));
+		assert_eq!(UseStorage::students_info(2).unwrap().name, name("bob"));
+		assert_eq!(UseStorage::student_owner(2), Some(2));
+		assert_eq!(UseStorage::dorms(1), Some(DormDetail { capacity: 2, occupied: 2 }));
+		assert_eq!(UseStorage::student_bed(2), Some((1, 1)));
+		assert_eq!(UseStorage::dorm_info(1, 0), Some(1));
+	});
+}
+
+#[test]
+#[should_panic(expected = "duplicate bed 0 in dorm 1")]
+fn genesis_rejects_duplicate_beds() {
+	genesis_ext(vec![(1, 0, 1), (1, 0, 2)]);
+}
+
+#[test]
+#[should_panic(expected = "student 1 assigned twice")]
+fn genesis_rejects_student_in_two_beds() {
+	genesis_ext(vec![(1, 0, 1), (1, 1, 1)]);
+}
+
+// 6. 查询接口
+#[test]
+fn queries_for_runtime_api() {
+	genesis_ext(vec![(1, 1, 2), (1, 0, 1)]).execute_with(|| {
+		let (owner, profile) = UseStorage::student(1).unwrap();
+		assert_eq!(owner, 1);
+		assert_eq!(profile, StudentProfile { name: b"alice".to_vec(), major: None, grade: None });
+		assert_eq!(UseStorage::student(9), None);
+		assert_eq!(UseStorage::dorm_occupancy(1), vec![(0, 1), (1, 2)]);
+		assert_eq!(UseStorage::dorm_occupancy(2), vec![]);
+	});
+}
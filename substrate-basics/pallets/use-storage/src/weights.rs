@@ -0,0 +1,244 @@
+//! Weights for pallet_use_storage
+//!
+//! These have not been benchmarked. `scripts/benchmark_weights.sh` overwrites this file with the
+//! measured values; until then each call costs a flat `10_000` plus its storage accesses, and
+//! `auto_assign` grows with the students and dorms it has to touch.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use frame_support::sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_use_storage.
+pub trait WeightInfo {
+	fn set_students_info() -> Weight;
+	fn set_students_profile() -> Weight;
+	fn define_dorm() -> Weight;
+	fn assign() -> Weight;
+	fn vacate() -> Weight;
+	fn swap() -> Weight;
+	fn auto_assign(s: u32, d: u32, ) -> Weight;
+	fn create_class() -> Weight;
+	fn close_class() -> Weight;
+	fn enroll() -> Weight;
+	fn withdraw() -> Weight;
+	fn add_registrar() -> Weight;
+	fn remove_registrar() -> Weight;
+}
+
+/// Weights for pallet_use_storage charged by the runtime.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: UseStorage StudentOwner (r:1 w:1)
+	// Storage: UseStorage StudentsInfo (r:1 w:1)
+	fn set_students_info() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage StudentsInfo (r:1 w:1)
+	fn set_students_profile() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage DormInfo (r:8 w:0)
+	// Storage: UseStorage Dorms (r:1 w:1)
+	fn define_dorm() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage StudentBed (r:1 w:1)
+	// Storage: UseStorage Dorms (r:1 w:1)
+	// Storage: UseStorage DormInfo (r:1 w:1)
+	fn assign() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage StudentBed (r:1 w:1)
+	// Storage: UseStorage DormInfo (r:0 w:1)
+	// Storage: UseStorage Dorms (r:1 w:1)
+	fn vacate() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:2 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage StudentBed (r:2 w:2)
+	// Storage: UseStorage DormInfo (r:0 w:2)
+	fn swap() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: UseStorage StudentsInfo (r:1 w:0)
+	// Storage: UseStorage StudentBed (r:1 w:1)
+	// Storage: UseStorage Dorms (r:2 w:1)
+	// Storage: UseStorage DormInfo (r:8 w:1)
+	fn auto_assign(s: u32, d: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads((11 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: UseStorage Classes (r:1 w:1)
+	fn create_class() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage Classes (r:1 w:1)
+	fn close_class() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage Enrollments (r:1 w:1)
+	// Storage: UseStorage Classes (r:1 w:1)
+	fn enroll() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage Enrollments (r:1 w:1)
+	// Storage: UseStorage Classes (r:1 w:1)
+	fn withdraw() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: UseStorage Registrars (r:1 w:1)
+	fn add_registrar() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage Registrars (r:1 w:1)
+	fn remove_registrar() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: UseStorage StudentOwner (r:1 w:1)
+	// Storage: UseStorage StudentsInfo (r:1 w:1)
+	fn set_students_info() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage StudentsInfo (r:1 w:1)
+	fn set_students_profile() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage DormInfo (r:8 w:0)
+	// Storage: UseStorage Dorms (r:1 w:1)
+	fn define_dorm() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage StudentBed (r:1 w:1)
+	// Storage: UseStorage Dorms (r:1 w:1)
+	// Storage: UseStorage DormInfo (r:1 w:1)
+	fn assign() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage StudentBed (r:1 w:1)
+	// Storage: UseStorage DormInfo (r:0 w:1)
+	// Storage: UseStorage Dorms (r:1 w:1)
+	fn vacate() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:2 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage StudentBed (r:2 w:2)
+	// Storage: UseStorage DormInfo (r:0 w:2)
+	fn swap() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: UseStorage StudentsInfo (r:1 w:0)
+	// Storage: UseStorage StudentBed (r:1 w:1)
+	// Storage: UseStorage Dorms (r:2 w:1)
+	// Storage: UseStorage DormInfo (r:8 w:1)
+	fn auto_assign(s: u32, d: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads((11 as Weight).saturating_mul(s as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(s as Weight)))
+	}
+	// Storage: UseStorage Classes (r:1 w:1)
+	fn create_class() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage Classes (r:1 w:1)
+	fn close_class() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage Enrollments (r:1 w:1)
+	// Storage: UseStorage Classes (r:1 w:1)
+	fn enroll() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: UseStorage StudentOwner (r:1 w:0)
+	// Storage: UseStorage Registrars (r:1 w:0)
+	// Storage: UseStorage Enrollments (r:1 w:1)
+	// Storage: UseStorage Classes (r:1 w:1)
+	fn withdraw() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: UseStorage Registrars (r:1 w:1)
+	fn add_registrar() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: UseStorage Registrars (r:1 w:1)
+	fn remove_registrar() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -11,7 +11,11 @@ use pallet_grandpa::{
 };
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use sp_core::{
+	crypto::KeyTypeId,
+	u32_trait::{_1, _2},
+	OpaqueMetadata,
+};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
@@ -26,13 +30,14 @@ use sp_version::RuntimeVersion;
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{KeyOwnerProofSystem, Randomness, StorageInfo},
+	traits::{Contains, KeyOwnerProofSystem, PalletInfoAccess, Randomness, StorageInfo},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
 	StorageValue,
 };
+use frame_system::{EnsureOneOf, EnsureRoot};
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::CurrencyAdapter;
@@ -98,7 +103,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 105,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -140,11 +145,26 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
 }
 
+/// Pallets `Pause` refuses to pause and `BaseCallFilter` always lets through. `Timestamp`
+/// provides an inherent every block must include; `System`, `Sudo` and `Council` are needed to
+/// handle an incident without a runtime upgrade.
+pub struct UnpausablePallets;
+impl Contains<Vec<u8>> for UnpausablePallets {
+	fn contains(pallet_name: &Vec<u8>) -> bool {
+		[System::name(), Timestamp::name(), Sudo::name(), Council::name()]
+			.iter()
+			.any(|name| name.as_bytes() == pallet_name.as_slice())
+	}
+}
+
+/// Rejects calls paused through `Pause`, except those of `Pause` itself and `UnpausablePallets`.
+pub type BaseCallFilter = pallet_pause::NotPaused<Runtime>;
+
 // Configure FRAME pallets to include in runtime.
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = BaseCallFilter;
 	/// Block & extrinsics weights: base values and limits.
 	type BlockWeights = BlockWeights;
 	/// The maximum length of a block (in bytes).
@@ -267,6 +287,44 @@ impl pallet_transaction_payment::Config for Runtime {
 	type FeeMultiplierUpdate = ();
 }
 
+parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = DAYS;
+	pub const CouncilMaxProposals: u32 = 100;
+	pub const CouncilMaxMembers: u32 = 100;
+}
+
+type CouncilCollective = pallet_collective::Instance1;
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type Origin = Origin;
+	type Proposal = Call;
+	type Event = Event;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = CouncilMaxProposals;
+	type MaxMembers = CouncilMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+}
+
+/// Admin origin of the custom pallets: root (sudo) or more than half of the council.
+pub type EnsureRootOrHalfCouncil = EnsureOneOf<
+	AccountId,
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionMoreThan<_1, _2, AccountId, CouncilCollective>,
+>;
+
+impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
+	type Event = Event;
+	type AddOrigin = EnsureRootOrHalfCouncil;
+	type RemoveOrigin = EnsureRootOrHalfCouncil;
+	type SwapOrigin = EnsureRootOrHalfCouncil;
+	type ResetOrigin = EnsureRootOrHalfCouncil;
+	type PrimeOrigin = EnsureRootOrHalfCouncil;
+	type MembershipInitialized = Council;
+	type MembershipChanged = Council;
+	type MaxMembers = CouncilMaxMembers;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_sudo::Config for Runtime {
 	type Event = Event;
 	type Call = Call;
@@ -278,8 +336,30 @@ impl pallet_template::Config for Runtime {
 	type MaxBytesInHash = frame_support::traits::ConstU32<64>;
 }
 
+parameter_types! {
+	/// Students registered before owners were tracked are handed to the sudo key, which the
+	/// storage migration also makes a registrar.
+	pub LegacyStudentOwner: AccountId = Sudo::key();
+}
+
 impl pallet_use_storage::Config for Runtime {
 	type Event = Event;
+	type AdminOrigin = EnsureRootOrHalfCouncil;
+	type MaxNameLength = frame_support::traits::ConstU32<64>;
+	type MaxBedsPerDorm = frame_support::traits::ConstU32<8>;
+	type MaxAutoAssign = frame_support::traits::ConstU32<64>;
+	type MaxAutoAssignDorms = frame_support::traits::ConstU32<128>;
+	type MaxClassCapacity = frame_support::traits::ConstU32<100>;
+	type LegacyStudentOwner = LegacyStudentOwner;
+	type WeightInfo = pallet_use_storage::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_pause::Config for Runtime {
+	type Event = Event;
+	type PauseOrigin = EnsureRootOrHalfCouncil;
+	type UnpausablePallets = UnpausablePallets;
+	type MaxNameLength = frame_support::traits::ConstU32<64>;
+	type WeightInfo = pallet_pause::weights::SubstrateWeight<Runtime>;
 }
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
@@ -288,17 +368,23 @@ construct_runtime!(
 		NodeBlock = opaque::Block,
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
-		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
-		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
-		Aura: pallet_aura::{Pallet, Config<T>},
-		Grandpa: pallet_grandpa::{Pallet, Call, Storage, Config, Event},
-		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		TransactionPayment: pallet_transaction_payment::{Pallet, Storage},
-		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>},
+		// Pallet indices are pinned so that calls and events already encoded against a
+		// running chain keep decoding. New pallets take the next free index.
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>} = 0,
+		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage} = 1,
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent} = 2,
+		Aura: pallet_aura::{Pallet, Config<T>} = 3,
+		Grandpa: pallet_grandpa::{Pallet, Call, Storage, Config, Event} = 4,
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>} = 5,
+		TransactionPayment: pallet_transaction_payment::{Pallet, Storage} = 6,
+		Sudo: pallet_sudo::{Pallet, Call, Config<T>, Storage, Event<T>} = 7,
+		// Storage is prefixed by the pallet name, so `UseStorage` must keep its name.
+		UseStorage: pallet_use_storage::{Pallet, Call, Storage, Config<T>, Event<T>} = 8,
 		// Include the custom logic from the pallet-template in the runtime.
-		// TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>},
-		UseStorage: pallet_use_storage::{Pallet, Call, Storage, Event<T>},
+		TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>} = 9,
+		Council: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 10,
+		CouncilMembership: pallet_membership::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>} = 11,
+		Pause: pallet_pause::{Pallet, Call, Storage, Event<T>} = 12,
 	}
 );
 
@@ -460,6 +546,20 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_use_storage_runtime_api::UseStorageApi<Block, AccountId> for Runtime {
+		fn student(student_number: u32) -> Option<(AccountId, pallet_use_storage::StudentProfile<Vec<u8>>)> {
+			UseStorage::student(student_number)
+		}
+
+		fn dorm_occupancy(dorm_number: u32) -> Vec<(u32, u32)> {
+			UseStorage::dorm_occupancy(dorm_number)
+		}
+
+		fn class(class_number: u32) -> Option<pallet_use_storage::ClassDetail> {
+			UseStorage::classes(class_number)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -476,6 +576,10 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
 			list_benchmark!(list, extra, pallet_template, TemplateModule);
+			list_benchmark!(list, extra, pallet_use_storage, UseStorage);
+			list_benchmark!(list, extra, pallet_collective, Council);
+			list_benchmark!(list, extra, pallet_membership, CouncilMembership);
+			list_benchmark!(list, extra, pallet_pause, Pause);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -510,6 +614,10 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
 			add_benchmark!(params, batches, pallet_template, TemplateModule);
+			add_benchmark!(params, batches, pallet_use_storage, UseStorage);
+			add_benchmark!(params, batches, pallet_collective, Council);
+			add_benchmark!(params, batches, pallet_membership, CouncilMembership);
+			add_benchmark!(params, batches, pallet_pause, Pause);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -0,0 +1,28 @@
+#!/usr/bin/env bash
+# Regenerates the weights.rs of the local pallets from their benchmarks.
+# Run from the repository root on the reference hardware, not on a laptop or CI runner.
+set -e
+
+# crate name:directory of every pallet whose weights.rs is generated
+PALLETS=(
+	"pallet_pause:pallets/pause"
+	"pallet_use_storage:pallets/use-storage"
+)
+
+cargo build --release --features runtime-benchmarks
+
+for entry in "${PALLETS[@]}"; do
+	pallet="${entry%%:*}"
+	dir="${entry#*:}"
+	echo "*** Benchmarking $pallet"
+	./target/release/node-template benchmark \
+		--chain=dev \
+		--pallet="$pallet" \
+		--extrinsic='*' \
+		--steps=50 \
+		--repeat=20 \
+		--execution=wasm \
+		--wasm-execution=compiled \
+		--template=./scripts/frame-weight-template.hbs \
+		--output="./$dir/src/weights.rs"
+done